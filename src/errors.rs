@@ -4,18 +4,167 @@ use axum::{
     response::{IntoResponse, Response},
 };
 
-use http::StatusCode;
+use http::{HeaderName, HeaderValue, StatusCode};
 
-use serde::Serialize;
+use serde::de::{self, MapAccess, Visitor};
+use serde::ser::SerializeMap;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize)]
+use std::collections::HashMap;
+use std::sync::OnceLock;
+use std::time::Duration;
+
+static APP_STATUS_MAP: OnceLock<HashMap<u16, StatusCode>> = OnceLock::new();
+
+/// Register a process-wide mapping from application numeric statuses to HTTP statuses,
+/// consulted by [`StatusKind::to_http_status`].
+///
+/// The map can only be set once; subsequent calls return the rejected map as `Err`.
+pub fn set_app_status_map(
+    map: HashMap<u16, StatusCode>,
+) -> std::result::Result<(), HashMap<u16, StatusCode>> {
+    APP_STATUS_MAP.set(map)
+}
+
+static APP_ERROR_MAP: OnceLock<HashMap<u16, String>> = OnceLock::new();
+
+/// Register a process-wide mapping from application numeric statuses to default
+/// `error` slugs, used by [`AppError::to_error_response`] when `error` is empty.
+///
+/// The map can only be set once; subsequent calls return the rejected map as `Err`.
+pub fn set_app_error_map(
+    map: HashMap<u16, String>,
+) -> std::result::Result<(), HashMap<u16, String>> {
+    APP_ERROR_MAP.set(map)
+}
+
+static ERROR_FIELD_NAMES: OnceLock<(&'static str, &'static str)> = OnceLock::new();
+
+/// Keys [`ErrorResponse`] already uses for its other fields.
+const RESERVED_FIELD_NAMES: &[&str] = &["code", "details", "trace_id", "timestamp", "errors"];
+
+/// Register process-wide names for the `error` and `message` keys of every
+/// serialized [`ErrorResponse`], e.g. `("error_code", "error_message")`. The
+/// remaining keys keep their names, and the OpenAPI schema always documents the
+/// defaults.
+///
+/// The names can only be set once. The rejected pair is returned as `Err` on
+/// subsequent calls, when the two names are equal, or when either one is
+/// already used by another field (`code`, `details`, `trace_id`, `timestamp`,
+/// `errors`).
+pub fn set_error_field_names(
+    error: &'static str,
+    message: &'static str,
+) -> std::result::Result<(), (&'static str, &'static str)> {
+    if error == message
+        || RESERVED_FIELD_NAMES.contains(&error)
+        || RESERVED_FIELD_NAMES.contains(&message)
+    {
+        return Err((error, message));
+    }
+    ERROR_FIELD_NAMES.set((error, message))
+}
+
+/// Reads a trace id out of a `tracing::Span`, see [`set_trace_id_extractor`].
+#[cfg(feature = "axum")]
+pub type TraceIdExtractor = fn(&tracing::Span) -> Option<String>;
+
+#[cfg(feature = "axum")]
+static TRACE_ID_EXTRACTOR: OnceLock<TraceIdExtractor> = OnceLock::new();
+
+/// Register how the `axum` `IntoResponse` impl reads a trace id from the current
+/// `tracing::Span` when none was set with [`AppError::with_trace_id`], e.g. by
+/// pulling the OpenTelemetry trace id out of the span's context. Without an
+/// extractor, such responses carry no `trace_id`.
+///
+/// The extractor can only be set once; subsequent calls return the rejected one as `Err`.
+#[cfg(feature = "axum")]
+pub fn set_trace_id_extractor(
+    extractor: TraceIdExtractor,
+) -> std::result::Result<(), TraceIdExtractor> {
+    TRACE_ID_EXTRACTOR.set(extractor)
+}
+
+/// Serialized with the `None` fields omitted, using the key names registered via
+/// [`set_error_field_names`] for `error` and `message`.
+#[derive(Debug)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct ErrorResponse {
+    #[cfg_attr(feature = "openapi", schema(example = "USER_NOT_FOUND"))]
     pub error: String,
+    #[cfg_attr(feature = "openapi", schema(example = "no user with id 42"))]
     pub message: String,
+    /// Stable machine-readable code (e.g. `USER_NOT_FOUND`) clients can switch on.
+    pub code: Option<String>,
+    /// Structured extra information, e.g. field-level validation errors.
+    pub details: Option<serde_json::Value>,
+    /// Correlation id linking this failure to server-side logs.
+    pub trace_id: Option<String>,
+    /// When the error was generated, as an RFC 3339 string.
+    pub timestamp: Option<String>,
+    /// Independent per-field errors, e.g. from form validation.
+    pub errors: Option<Vec<FieldError>>,
+}
+
+impl Serialize for ErrorResponse {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        let (error_key, message_key) = ERROR_FIELD_NAMES
+            .get()
+            .copied()
+            .unwrap_or(("error", "message"));
+
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry(error_key, &self.error)?;
+        map.serialize_entry(message_key, &self.message)?;
+        if let Some(code) = &self.code {
+            map.serialize_entry("code", code)?;
+        }
+        if let Some(details) = &self.details {
+            map.serialize_entry("details", details)?;
+        }
+        if let Some(trace_id) = &self.trace_id {
+            map.serialize_entry("trace_id", trace_id)?;
+        }
+        if let Some(timestamp) = &self.timestamp {
+            map.serialize_entry("timestamp", timestamp)?;
+        }
+        if let Some(errors) = &self.errors {
+            map.serialize_entry("errors", errors)?;
+        }
+        map.end()
+    }
+}
+
+/// A single field-level error in [`ErrorResponse::errors`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct FieldError {
+    #[cfg_attr(feature = "openapi", schema(example = "email"))]
+    pub field: String,
+    #[cfg_attr(feature = "openapi", schema(example = "is required"))]
+    pub message: String,
+}
+
+impl FieldError {
+    pub fn new(field: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            field: field.into(),
+            message: message.into(),
+        }
+    }
+}
+
+impl From<(String, String)> for FieldError {
+    fn from((field, message): (String, String)) -> Self {
+        Self::new(field, message)
+    }
 }
 
 /// Extensible status kind: either a real HTTP status or an application numeric status.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum StatusKind {
     Http(StatusCode),
     App(u16),
@@ -23,11 +172,44 @@ pub enum StatusKind {
 
 impl StatusKind {
     /// Map to an HTTP status for transport adapters. Application numeric statuses
-    /// default to 500 (Internal Server Error) unless adapters provide a different mapping.
+    /// use the map registered via [`set_app_status_map`], defaulting to 500
+    /// (Internal Server Error) when no mapping is registered.
     pub fn to_http_status(&self) -> StatusCode {
+        match APP_STATUS_MAP.get() {
+            Some(map) => self.to_http_status_with(map),
+            None => self.to_http_status_with(&HashMap::new()),
+        }
+    }
+
+    /// The raw numeric status, regardless of variant.
+    pub fn as_u16(&self) -> u16 {
+        match self {
+            StatusKind::Http(c) => c.as_u16(),
+            StatusKind::App(n) => *n,
+        }
+    }
+
+    /// Interpret `n` as an HTTP status when it is a standard code (one with a
+    /// canonical reason phrase), otherwise as an application numeric status.
+    ///
+    /// Unlike `From<u16>`, which always yields [`StatusKind::App`], this makes
+    /// `StatusKind::from_http_u16(404)` a real `404 Not Found`.
+    pub fn from_http_u16(n: u16) -> StatusKind {
+        match StatusCode::from_u16(n) {
+            Ok(code) if code.canonical_reason().is_some() => StatusKind::Http(code),
+            _ => StatusKind::App(n),
+        }
+    }
+
+    /// Map to an HTTP status using an explicit application status map. Unmapped
+    /// application numeric statuses default to 500 (Internal Server Error).
+    pub fn to_http_status_with(&self, map: &HashMap<u16, StatusCode>) -> StatusCode {
         match self {
             StatusKind::Http(c) => *c,
-            StatusKind::App(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            StatusKind::App(n) => map
+                .get(n)
+                .copied()
+                .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR),
         }
     }
 }
@@ -38,17 +220,122 @@ impl From<StatusCode> for StatusKind {
     }
 }
 
+/// Serializes standard `Http` statuses as a bare integer, and everything else as a
+/// tagged object (`{"app": n}` or `{"http": n}`) so that deserializing is lossless.
+impl Serialize for StatusKind {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        let (tag, n) = match self {
+            StatusKind::Http(c) if c.canonical_reason().is_some() => {
+                return serializer.serialize_u16(c.as_u16());
+            }
+            StatusKind::Http(c) => ("http", c.as_u16()),
+            StatusKind::App(n) => ("app", *n),
+        };
+
+        let mut map = serializer.serialize_map(Some(1))?;
+        map.serialize_entry(tag, &n)?;
+        map.end()
+    }
+}
+
+/// Accepts a bare integer (resolved with [`StatusKind::from_http_u16`]) or a tagged
+/// object, either `{"app": n}` or `{"http": n}`.
+impl<'de> Deserialize<'de> for StatusKind {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        struct StatusKindVisitor;
+
+        impl<'de> Visitor<'de> for StatusKindVisitor {
+            type Value = StatusKind;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str(r#"a status code or an object like {"app": 1001}"#)
+            }
+
+            fn visit_u64<E: de::Error>(self, v: u64) -> std::result::Result<StatusKind, E> {
+                let n = u16::try_from(v).map_err(|_| E::custom("status out of range"))?;
+                Ok(StatusKind::from_http_u16(n))
+            }
+
+            fn visit_i64<E: de::Error>(self, v: i64) -> std::result::Result<StatusKind, E> {
+                let n = u16::try_from(v).map_err(|_| E::custom("status out of range"))?;
+                Ok(StatusKind::from_http_u16(n))
+            }
+
+            fn visit_map<A: MapAccess<'de>>(
+                self,
+                mut map: A,
+            ) -> std::result::Result<StatusKind, A::Error> {
+                let (tag, n) = map
+                    .next_entry::<String, u16>()?
+                    .ok_or_else(|| de::Error::custom("expected an `app` or `http` key"))?;
+                if map.next_key::<de::IgnoredAny>()?.is_some() {
+                    return Err(de::Error::custom("expected exactly one of `app`/`http`"));
+                }
+
+                match tag.as_str() {
+                    "app" => Ok(StatusKind::App(n)),
+                    "http" => StatusCode::from_u16(n)
+                        .map(StatusKind::Http)
+                        .map_err(de::Error::custom),
+                    other => Err(de::Error::unknown_field(other, &["app", "http"])),
+                }
+            }
+        }
+
+        deserializer.deserialize_any(StatusKindVisitor)
+    }
+}
+
+/// Always produces [`StatusKind::App`]; use [`StatusKind::from_http_u16`] to
+/// prefer a standard HTTP status.
 impl From<u16> for StatusKind {
     fn from(n: u16) -> Self {
         StatusKind::App(n)
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct AppError {
     pub status: StatusKind,
     pub error: String,
     pub message: String,
+    /// Optional envelope fields, boxed so `Result<T, AppError>` stays small on the `Ok` path.
+    pub extras: Box<ErrorExtras>,
+}
+
+/// The optional parts of an [`AppError`], set through its `with_*` builders.
+#[derive(Debug, Default)]
+pub struct ErrorExtras {
+    pub code: Option<String>,
+    pub details: Option<serde_json::Value>,
+    /// Extra headers appended to the response by transport adapters.
+    pub headers: Vec<(HeaderName, HeaderValue)>,
+    pub trace_id: Option<String>,
+    pub timestamp: Option<String>,
+    pub errors: Option<Vec<FieldError>>,
+    /// The underlying error, kept for server-side logging and never sent to clients.
+    pub source: Option<Box<dyn std::error::Error + Send + Sync>>,
+}
+
+/// Clones every field except `source`, which cannot be cloned and is left as
+/// `None` on the copy.
+impl Clone for ErrorExtras {
+    fn clone(&self) -> Self {
+        Self {
+            code: self.code.clone(),
+            details: self.details.clone(),
+            headers: self.headers.clone(),
+            trace_id: self.trace_id.clone(),
+            timestamp: self.timestamp.clone(),
+            errors: self.errors.clone(),
+            source: None,
+        }
+    }
 }
 
 impl AppError {
@@ -57,6 +344,137 @@ impl AppError {
             status,
             error: error.into(),
             message: message.into(),
+            extras: Box::default(),
+        }
+    }
+
+    /// Catch-all constructor deriving `error` from the status' canonical reason,
+    /// e.g. `404` becomes `"NOT_FOUND"`. Non-standard statuses use `"UNKNOWN_ERROR"`.
+    pub fn from_status(status: StatusCode, message: impl Into<String>) -> Self {
+        let error = match status.canonical_reason() {
+            Some(reason) => reason
+                .chars()
+                .filter_map(|c| match c {
+                    ' ' | '-' => Some('_'),
+                    c if c.is_ascii_alphanumeric() => Some(c.to_ascii_uppercase()),
+                    _ => None,
+                })
+                .collect(),
+            None => "UNKNOWN_ERROR".to_string(),
+        };
+
+        Self::new(StatusKind::Http(status), error, message)
+    }
+
+    /// Start an [`AppErrorBuilder`] for composing an error in one fluent chain.
+    ///
+    /// ```rust
+    /// use http::{StatusCode, header};
+    /// use morde_rs::errors::AppError;
+    ///
+    /// let err = AppError::build(StatusCode::CONFLICT)
+    ///     .error("DUPLICATE_EMAIL")
+    ///     .message("email is already registered")
+    ///     .code("E1002")
+    ///     .details(serde_json::json!({ "field": "email" }))
+    ///     .header(header::LOCATION, "/users/42".parse().unwrap())
+    ///     .build();
+    ///
+    /// assert_eq!(err.error, "DUPLICATE_EMAIL");
+    /// assert_eq!(err.extras.code.as_deref(), Some("E1002"));
+    /// assert_eq!(err.extras.headers.len(), 1);
+    /// ```
+    pub fn build(status: impl Into<StatusKind>) -> AppErrorBuilder {
+        AppErrorBuilder {
+            inner: Self::new(status.into(), "", ""),
+        }
+    }
+
+    /// Replace the status, e.g. to downgrade a 500 into a 503 as the error bubbles up.
+    pub fn with_status(mut self, status: impl Into<StatusKind>) -> Self {
+        self.status = status.into();
+        self
+    }
+
+    /// Prefix `message` with `"{ctx}: "` as the error bubbles up, keeping the
+    /// status and error slug.
+    pub fn context(mut self, ctx: impl Into<String>) -> Self {
+        self.message = format!("{}: {}", ctx.into(), self.message);
+        self
+    }
+
+    /// Attach a stable machine-readable code that is serialized as `code`.
+    pub fn with_code(mut self, code: impl Into<String>) -> Self {
+        self.extras.code = Some(code.into());
+        self
+    }
+
+    /// Attach structured details that are serialized alongside `error` and `message`.
+    pub fn with_details(mut self, details: serde_json::Value) -> Self {
+        self.extras.details = Some(details);
+        self
+    }
+
+    /// Attach a trace/correlation id that is serialized as `trace_id`.
+    ///
+    /// When unset, the `axum` response asks the extractor registered via
+    /// [`set_trace_id_extractor`] for one from the current `tracing::Span`.
+    pub fn with_trace_id(mut self, trace_id: impl Into<String>) -> Self {
+        self.extras.trace_id = Some(trace_id.into());
+        self
+    }
+
+    /// Stamp the error with the current UTC time, serialized as an RFC 3339 `timestamp`.
+    #[cfg(feature = "time")]
+    pub fn with_timestamp(mut self) -> Self {
+        self.extras.timestamp = time::OffsetDateTime::now_utc()
+            .format(&time::format_description::well_known::Rfc3339)
+            .ok();
+        self
+    }
+
+    /// Append a header to be sent with the error response.
+    pub fn with_header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.extras.headers.push((name, value));
+        self
+    }
+
+    /// Whether the mapped HTTP status is a `4xx`.
+    pub fn is_client_error(&self) -> bool {
+        self.status.to_http_status().is_client_error()
+    }
+
+    /// Whether the mapped HTTP status is a `5xx`.
+    pub fn is_server_error(&self) -> bool {
+        self.status.to_http_status().is_server_error()
+    }
+
+    /// Build the JSON body sent to clients by transport adapters.
+    ///
+    /// An empty `error` falls back to the HTTP status' canonical reason phrase
+    /// (e.g. `"Not Found"`), or for application statuses to the slug registered
+    /// via [`set_app_error_map`].
+    pub fn to_error_response(&self) -> ErrorResponse {
+        let error = match &self.status {
+            StatusKind::Http(c) if self.error.is_empty() => {
+                c.canonical_reason().unwrap_or_default().to_string()
+            }
+            StatusKind::App(n) if self.error.is_empty() => APP_ERROR_MAP
+                .get()
+                .and_then(|map| map.get(n))
+                .cloned()
+                .unwrap_or_default(),
+            _ => self.error.clone(),
+        };
+
+        ErrorResponse {
+            error,
+            message: self.message.clone(),
+            code: self.extras.code.clone(),
+            details: self.extras.details.clone(),
+            trace_id: self.extras.trace_id.clone(),
+            timestamp: self.extras.timestamp.clone(),
+            errors: self.extras.errors.clone(),
         }
     }
 
@@ -84,36 +502,490 @@ impl AppError {
         Self::new(StatusKind::Http(StatusCode::CONFLICT), error, message)
     }
 
-    // pub fn forbidden(message: impl Into<String>) -> Self {
-    //     Self::new(StatusCode::FORBIDDEN, "FORBIDDEN", message)
-    // }
+    pub fn gone(error: impl Into<String>, message: impl Into<String>) -> Self {
+        Self::new(StatusKind::Http(StatusCode::GONE), error, message)
+    }
+
+    pub fn unprocessable_entity(error: impl Into<String>, message: impl Into<String>) -> Self {
+        Self::new(StatusKind::Http(StatusCode::UNPROCESSABLE_ENTITY), error, message)
+    }
+
+    pub fn precondition_failed(error: impl Into<String>, message: impl Into<String>) -> Self {
+        Self::new(StatusKind::Http(StatusCode::PRECONDITION_FAILED), error, message)
+    }
+
+    pub fn locked(message: impl Into<String>) -> Self {
+        Self::new(StatusKind::Http(StatusCode::LOCKED), "LOCKED", message)
+    }
+
+    pub fn forbidden(message: impl Into<String>) -> Self {
+        Self::new(StatusKind::Http(StatusCode::FORBIDDEN), "FORBIDDEN", message)
+    }
+
+    pub fn service_unavailable(message: impl Into<String>) -> Self {
+        Self::new(
+            StatusKind::Http(StatusCode::SERVICE_UNAVAILABLE),
+            "SERVICE_UNAVAILABLE",
+            message,
+        )
+    }
+
+    pub fn bad_gateway(message: impl Into<String>) -> Self {
+        Self::new(
+            StatusKind::Http(StatusCode::BAD_GATEWAY),
+            "BAD_GATEWAY",
+            message,
+        )
+    }
+
+    pub fn not_implemented(message: impl Into<String>) -> Self {
+        Self::new(
+            StatusKind::Http(StatusCode::NOT_IMPLEMENTED),
+            "NOT_IMPLEMENTED",
+            message,
+        )
+    }
+
+    /// `422` carrying several independent field errors, serialized as `errors`.
+    ///
+    /// Accepts [`FieldError`]s or `(field, message)` string pairs.
+    pub fn validation(errors: impl IntoIterator<Item = impl Into<FieldError>>) -> Self {
+        let mut err = Self::new(
+            StatusKind::Http(StatusCode::UNPROCESSABLE_ENTITY),
+            "VALIDATION",
+            "Validation failed",
+        );
+        err.extras.errors = Some(errors.into_iter().map(Into::into).collect());
+        err
+    }
+
+    /// `413` reporting the accepted body size, also exposed as `details.limit_bytes`.
+    pub fn payload_too_large(limit_bytes: u64) -> Self {
+        Self::new(
+            StatusKind::Http(StatusCode::PAYLOAD_TOO_LARGE),
+            "PAYLOAD_TOO_LARGE",
+            format!("request body exceeds {limit_bytes} bytes"),
+        )
+        .with_details(serde_json::json!({ "limit_bytes": limit_bytes }))
+    }
+
+    /// `405` with an `Allow` header listing the permitted methods.
+    pub fn method_not_allowed(allowed: &[http::Method]) -> Self {
+        let allow = allowed
+            .iter()
+            .map(http::Method::as_str)
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let err = Self::new(
+            StatusKind::Http(StatusCode::METHOD_NOT_ALLOWED),
+            "METHOD_NOT_ALLOWED",
+            "Method not allowed",
+        );
+
+        match HeaderValue::from_str(&allow) {
+            Ok(value) => err.with_header(http::header::ALLOW, value),
+            Err(_) => err,
+        }
+    }
+
+    pub fn too_many_requests(message: impl Into<String>, retry_after: Option<Duration>) -> Self {
+        let err = Self::new(
+            StatusKind::Http(StatusCode::TOO_MANY_REQUESTS),
+            "TOO_MANY_REQUESTS",
+            message,
+        );
+
+        match retry_after {
+            Some(retry_after) => {
+                // Round up so a sub-second delay never advertises `Retry-After: 0`.
+                let secs = retry_after
+                    .as_secs()
+                    .saturating_add(u64::from(retry_after.subsec_nanos() > 0));
+                err.with_header(http::header::RETRY_AFTER, secs.into())
+            }
+            None => err,
+        }
+    }
+}
+
+/// Fluent builder returned by [`AppError::build`].
+#[derive(Debug)]
+pub struct AppErrorBuilder {
+    inner: AppError,
+}
+
+impl AppErrorBuilder {
+    pub fn error(mut self, error: impl Into<String>) -> Self {
+        self.inner.error = error.into();
+        self
+    }
+
+    pub fn message(mut self, message: impl Into<String>) -> Self {
+        self.inner.message = message.into();
+        self
+    }
+
+    pub fn code(mut self, code: impl Into<String>) -> Self {
+        self.inner = self.inner.with_code(code);
+        self
+    }
+
+    pub fn details(mut self, details: serde_json::Value) -> Self {
+        self.inner = self.inner.with_details(details);
+        self
+    }
+
+    pub fn header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.inner = self.inner.with_header(name, value);
+        self
+    }
+
+    pub fn build(self) -> AppError {
+        self.inner
+    }
+}
+
+/// Mask all but the last four characters of `secret`, for logging tokens and keys.
+///
+/// Secrets of four characters or fewer are masked entirely.
+///
+/// ```rust
+/// assert_eq!(morde_rs::errors::redact("abcdef1234"), "******1234");
+/// ```
+pub fn redact(secret: &str) -> String {
+    let len = secret.chars().count();
+    let visible = if len > 4 { 4 } else { 0 };
+
+    secret
+        .chars()
+        .enumerate()
+        .map(|(i, c)| if i < len - visible { '*' } else { c })
+        .collect()
 }
 
 /// Convenient crate-local result alias.
 pub type Result<T> = std::result::Result<T, AppError>;
 
 #[cfg(feature = "axum")]
+/// Also emits a `tracing` event with `status`, `error`, `message` and, when
+/// present, the underlying `source` error as structured fields, at `error` level
+/// for server errors and `warn` otherwise.
 impl IntoResponse for AppError {
     fn into_response(self) -> Response {
-        let error_response = ErrorResponse {
-            error: self.error,
-            message: self.message,
+        let status = self.status.to_http_status();
+        if status.is_server_error() {
+            tracing::event!(
+                tracing::Level::ERROR,
+                status = status.as_u16(),
+                error = %self.error,
+                message = %self.message,
+                source = self.extras.source.as_deref().map(tracing::field::display),
+            );
+        } else {
+            tracing::event!(
+                tracing::Level::WARN,
+                status = status.as_u16(),
+                error = %self.error,
+                message = %self.message,
+                source = self.extras.source.as_deref().map(tracing::field::display),
+            );
+        }
+
+        let mut body = self.to_error_response();
+        if body.trace_id.is_none() {
+            body.trace_id = TRACE_ID_EXTRACTOR
+                .get()
+                .and_then(|extract| extract(&tracing::Span::current()));
+        }
+
+        let mut response = (status, Json(body)).into_response();
+
+        for (name, value) in self.extras.headers {
+            response.headers_mut().append(name, value);
+        }
+
+        response
+    }
+}
+
+/// Uniform handler return type: `Ok` becomes `200` with a JSON body and `Err`
+/// uses [`AppError`]'s response.
+///
+/// ```rust
+/// use morde_rs::errors::{ApiResult, AppError};
+///
+/// async fn get_user() -> ApiResult<serde_json::Value> {
+///     ApiResult(Err(AppError::not_found("USER_NOT_FOUND", "no such user")))
+/// }
+/// ```
+#[cfg(feature = "axum")]
+pub struct ApiResult<T>(pub Result<T>);
+
+#[cfg(feature = "axum")]
+impl<T> From<Result<T>> for ApiResult<T> {
+    fn from(result: Result<T>) -> Self {
+        ApiResult(result)
+    }
+}
+
+#[cfg(feature = "axum")]
+impl<T: Serialize> IntoResponse for ApiResult<T> {
+    fn into_response(self) -> Response {
+        match self.0 {
+            Ok(value) => Json(value).into_response(),
+            Err(err) => err.into_response(),
+        }
+    }
+}
+
+/// Compares `status`, `error` and `message` only; the remaining envelope fields
+/// and the `source` error are ignored.
+impl PartialEq for AppError {
+    fn eq(&self, other: &Self) -> bool {
+        self.status == other.status && self.error == other.error && self.message == other.message
+    }
+}
+
+impl std::fmt::Display for AppError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.error, self.message)
+    }
+}
+
+#[cfg(feature = "actix")]
+impl actix_web::ResponseError for AppError {
+    fn status_code(&self) -> actix_web::http::StatusCode {
+        actix_web::http::StatusCode::from_u16(self.status.to_http_status().as_u16())
+            .unwrap_or(actix_web::http::StatusCode::INTERNAL_SERVER_ERROR)
+    }
+
+    fn error_response(&self) -> actix_web::HttpResponse {
+        let mut response = actix_web::HttpResponse::build(self.status_code());
+
+        for (name, value) in &self.extras.headers {
+            response.append_header((name.as_str(), value.as_bytes()));
+        }
+
+        response.json(self.to_error_response())
+    }
+}
+
+#[cfg(feature = "poem")]
+impl poem::error::ResponseError for AppError {
+    fn status(&self) -> StatusCode {
+        self.status.to_http_status()
+    }
+
+    fn as_response(&self) -> poem::Response {
+        let mut response =
+            poem::IntoResponse::into_response(poem::web::Json(self.to_error_response()));
+        response.set_status(self.status());
+
+        for (name, value) in &self.extras.headers {
+            response.headers_mut().append(name, value.clone());
+        }
+
+        response
+    }
+}
+
+#[cfg(feature = "warp")]
+impl warp::reject::Reject for AppError {}
+
+/// `recover` handler turning a rejected [`AppError`] into its JSON response.
+///
+/// warp's own client rejections keep their status (e.g. `405` for a wrong
+/// method, `400` for a malformed query string), unmatched routes become a `404`,
+/// and any other rejection becomes a `500`.
+///
+/// ```rust,ignore
+/// let routes = api.recover(morde_rs::errors::recover);
+/// ```
+#[cfg(feature = "warp")]
+pub async fn recover(
+    err: warp::Rejection,
+) -> std::result::Result<impl warp::Reply, std::convert::Infallible> {
+    use warp::Reply;
+
+    let app_err = if let Some(app_err) = err.find::<AppError>() {
+        app_err.clone()
+    } else if let Some(app_err) = from_warp_rejection(&err) {
+        app_err
+    } else if err.is_not_found() {
+        AppError::not_found("NOT_FOUND", "Not found")
+    } else {
+        AppError::internal_server_error("Internal server error")
+    };
+
+    let mut response = warp::reply::with_status(
+        warp::reply::json(&app_err.to_error_response()),
+        app_err.status.to_http_status(),
+    )
+    .into_response();
+
+    for (name, value) in app_err.extras.headers {
+        response.headers_mut().append(name, value);
+    }
+
+    Ok(response)
+}
+
+/// Maps warp's built-in rejections to the status warp itself would reply with.
+/// `405` is checked last, matching warp's preference for any other rejection
+/// over a method mismatch.
+#[cfg(feature = "warp")]
+fn from_warp_rejection(err: &warp::Rejection) -> Option<AppError> {
+    use warp::reject::{
+        InvalidHeader, InvalidQuery, LengthRequired, MethodNotAllowed, MissingCookie,
+        MissingHeader, PayloadTooLarge, UnsupportedMediaType,
+    };
+
+    fn find<T: std::fmt::Display + 'static>(
+        err: &warp::Rejection,
+        status: StatusCode,
+    ) -> Option<AppError> {
+        err.find::<T>()
+            .map(|rejection| AppError::from_status(status, rejection.to_string()))
+    }
+
+    find::<PayloadTooLarge>(err, StatusCode::PAYLOAD_TOO_LARGE)
+        .or_else(|| find::<UnsupportedMediaType>(err, StatusCode::UNSUPPORTED_MEDIA_TYPE))
+        .or_else(|| find::<LengthRequired>(err, StatusCode::LENGTH_REQUIRED))
+        .or_else(|| find::<InvalidQuery>(err, StatusCode::BAD_REQUEST))
+        .or_else(|| find::<MissingHeader>(err, StatusCode::BAD_REQUEST))
+        .or_else(|| find::<InvalidHeader>(err, StatusCode::BAD_REQUEST))
+        .or_else(|| find::<MissingCookie>(err, StatusCode::BAD_REQUEST))
+        .or_else(|| find::<warp::body::BodyDeserializeError>(err, StatusCode::BAD_REQUEST))
+        .or_else(|| find::<MethodNotAllowed>(err, StatusCode::METHOD_NOT_ALLOWED))
+}
+
+#[cfg(feature = "tonic")]
+impl From<AppError> for tonic::Status {
+    fn from(err: AppError) -> Self {
+        let code = match err.status.to_http_status() {
+            StatusCode::NOT_FOUND => tonic::Code::NotFound,
+            StatusCode::UNAUTHORIZED => tonic::Code::Unauthenticated,
+            StatusCode::FORBIDDEN => tonic::Code::PermissionDenied,
+            StatusCode::CONFLICT => tonic::Code::AlreadyExists,
+            StatusCode::BAD_REQUEST => tonic::Code::InvalidArgument,
+            _ => tonic::Code::Internal,
         };
 
-        (self.status.to_http_status(), Json(error_response)).into_response()
+        tonic::Status::new(code, err.message)
+    }
+}
+
+impl std::error::Error for AppError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.extras.source
+            .as_deref()
+            .map(|err| err as &(dyn std::error::Error + 'static))
     }
 }
 
 impl From<Box<dyn std::error::Error + Send + Sync>> for AppError {
     fn from(err: Box<dyn std::error::Error + Send + Sync>) -> Self {
-        Self::internal_server_error(err.to_string())
+        let mut app_err = Self::internal_server_error("Internal server error");
+        app_err.extras.source = Some(err);
+        app_err
+    }
+}
+
+impl From<serde_json::Error> for AppError {
+    fn from(err: serde_json::Error) -> Self {
+        Self::bad_request("INVALID_JSON", err.to_string())
+    }
+}
+
+impl From<std::io::Error> for AppError {
+    fn from(err: std::io::Error) -> Self {
+        match err.kind() {
+            std::io::ErrorKind::NotFound => Self::not_found("NOT_FOUND", err.to_string()),
+            std::io::ErrorKind::PermissionDenied => Self::forbidden(err.to_string()),
+            _ => Self::internal_server_error(err.to_string()),
+        }
     }
 }
 
 #[cfg(feature = "surrealdb")]
 impl From<surrealdb::Error> for AppError {
     fn from(err: surrealdb::Error) -> Self {
-        Self::internal_server_error(format!("Database error: {err}"))
+        let mut app_err = Self::internal_server_error("Database error");
+        app_err.extras.source = Some(Box::new(err));
+        app_err
+    }
+}
+
+/// SQLSTATE / vendor codes for unique-constraint violations: Postgres, MySQL,
+/// and SQLite (`SQLITE_CONSTRAINT_UNIQUE` and `SQLITE_CONSTRAINT_PRIMARYKEY`).
+#[cfg(feature = "sqlx")]
+const UNIQUE_VIOLATION_CODES: &[&str] = &["23505", "1062", "2067", "1555"];
+
+#[cfg(feature = "sqlx")]
+impl From<sqlx::Error> for AppError {
+    fn from(err: sqlx::Error) -> Self {
+        let is_unique_violation = err
+            .as_database_error()
+            .and_then(|e| e.code())
+            .is_some_and(|code| UNIQUE_VIOLATION_CODES.contains(&code.as_ref()));
+
+        let mut app_err = if matches!(err, sqlx::Error::RowNotFound) {
+            Self::not_found("NOT_FOUND", "Record not found")
+        } else if is_unique_violation {
+            Self::conflict("CONFLICT", "Record already exists")
+        } else {
+            Self::internal_server_error("Database error")
+        };
+        app_err.extras.source = Some(Box::new(err));
+        app_err
+    }
+}
+
+#[cfg(feature = "redis")]
+impl From<redis::RedisError> for AppError {
+    fn from(err: redis::RedisError) -> Self {
+        let (status, message) =
+            if err.is_connection_refusal() || err.is_connection_dropped() || err.is_timeout() {
+                (StatusCode::SERVICE_UNAVAILABLE, "Cache unavailable")
+            } else {
+                (StatusCode::INTERNAL_SERVER_ERROR, "Cache error")
+            };
+
+        let mut app_err = Self::new(StatusKind::Http(status), "CACHE_ERROR", message);
+        app_err.extras.source = Some(Box::new(err));
+        app_err
+    }
+}
+
+#[cfg(feature = "validator")]
+/// Fills both `details` (field → list of messages) and the `errors` array.
+impl From<validator::ValidationErrors> for AppError {
+    fn from(errors: validator::ValidationErrors) -> Self {
+        let mut fields: Vec<(String, Vec<String>)> = errors
+            .field_errors()
+            .into_iter()
+            .map(|(field, errors)| {
+                let messages = errors
+                    .iter()
+                    .map(|e| e.message.as_deref().unwrap_or(&e.code).to_string())
+                    .collect();
+                (field.to_string(), messages)
+            })
+            .collect();
+        fields.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+
+        let details: serde_json::Map<String, serde_json::Value> = fields
+            .iter()
+            .map(|(field, messages)| (field.clone(), messages.clone().into()))
+            .collect();
+
+        Self::validation(fields.into_iter().flat_map(|(field, messages)| {
+            messages
+                .into_iter()
+                .map(move |message| FieldError::new(field.clone(), message))
+        }))
+        .with_details(details.into())
     }
 }
 
@@ -123,53 +995,297 @@ impl From<eyre::Report> for AppError {
         Self::internal_server_error(err.to_string())
     }
 }
+#[cfg(feature = "anyhow")]
+impl From<anyhow::Error> for AppError {
+    fn from(err: anyhow::Error) -> Self {
+        match err.downcast::<AppError>() {
+            Ok(app_err) => app_err,
+            Err(err) => Self::internal_server_error(err.to_string()),
+        }
+    }
+}
+
+#[cfg(feature = "reqwest")]
+impl From<reqwest::Error> for AppError {
+    fn from(err: reqwest::Error) -> Self {
+        let status = if err.is_connect() || err.is_timeout() {
+            StatusCode::SERVICE_UNAVAILABLE
+        } else {
+            err.status().unwrap_or(StatusCode::BAD_GATEWAY)
+        };
+
+        let mut app_err = Self::new(
+            StatusKind::Http(status),
+            "UPSTREAM_ERROR",
+            "Upstream request failed",
+        );
+        app_err.extras.source = Some(Box::new(err));
+        app_err
+    }
+}
+
+#[cfg(feature = "jwt")]
+impl From<jsonwebtoken::errors::Error> for AppError {
+    fn from(err: jsonwebtoken::errors::Error) -> Self {
+        use jsonwebtoken::errors::ErrorKind;
+
+        let error = match err.kind() {
+            ErrorKind::ExpiredSignature => "TOKEN_EXPIRED",
+            ErrorKind::InvalidSignature => "INVALID_SIGNATURE",
+            _ => "INVALID_TOKEN",
+        };
+
+        Self::new(
+            StatusKind::Http(StatusCode::UNAUTHORIZED),
+            error,
+            err.to_string(),
+        )
+    }
+}
+
+/// Build an [`AppError`] and log it through `tracing` in one step.
+///
+/// Each arm mirrors an `AppError` constructor and accepts an optional trailing
+/// internal message, which is only logged and never sent to the client.
+///
+/// Server errors log at `error` and everything else at `warn`. An `@ level`
+/// suffix on the arm name overrides this with any `tracing` level macro
+/// (`trace`, `debug`, `info`, `warn`, `error`).
+///
+/// Never pass tokens or other secrets as the internal message. The
+/// `unauthorized` arm accepts `; redacted: secret` instead, which logs the
+/// secret through [`redact`](crate::errors::redact).
+///
+/// # Example
+///
+/// ```rust
+/// let err = morde_rs::app_error!(bad_request, "INVALID_INPUT", "name is required");
+/// assert_eq!(err.error, "INVALID_INPUT");
+///
+/// let err = morde_rs::app_error!(
+///     bad_request,
+///     "INVALID_INPUT",
+///     "name is required",
+///     "payload.name was None"
+/// );
+/// assert_eq!(err.message, "name is required");
+///
+/// let err = morde_rs::app_error!(internal_server_error, "something went wrong");
+/// assert_eq!(err.error, "INTERNAL_SERVER_ERROR");
+///
+/// let err = morde_rs::app_error!(
+///     internal_server_error,
+///     "something went wrong",
+///     "connection pool exhausted"
+/// );
+/// assert_eq!(err.message, "something went wrong");
+///
+/// // Log a routine 404 at debug instead of warn.
+/// let err = morde_rs::app_error!(not_found @ debug, "USER_NOT_FOUND", "no such user");
+/// assert_eq!(err.error, "USER_NOT_FOUND");
+///
+/// // Use a status computed at runtime as-is.
+/// let status = morde_rs::errors::StatusKind::App(1001);
+/// let err = morde_rs::app_error!(kind(status), "RATE_LIMITED", "slow down");
+/// assert_eq!(err.status, status);
+///
+/// // Logs only the last four characters of the token.
+/// let token = "eyJhbGciOiJIUzI1NiJ9";
+/// let err = morde_rs::app_error!(unauthorized, "invalid token"; redacted: token);
+/// assert_eq!(err.message, "invalid token");
+/// ```
 #[macro_export]
 macro_rules! app_error {
-    (custom($n:expr), $error:expr, $msg:expr $(, $internal_msg:expr)?) => {{
-        tracing::warn!("App Status {}: {} - {}: {}", $n, $error, $msg, $internal_msg);
-        $crate::types::errors::AppError::new(
-            $crate::types::errors::StatusKind::App($n),
-            $error,
-            $msg,
-        )
+    (custom($n:expr) $(@ $level:ident)?, $error:expr, $msg:expr) => {{
+        $crate::app_error!(@log [$($level)? warn], "App Status {}: {} - {}", $n, $error, $msg);
+        $crate::errors::AppError::new($crate::errors::StatusKind::App($n), $error, $msg)
     }};
 
-    (http($status:expr), $error:expr, $msg:expr $(, $internal_msg:expr)?) => {{
-        tracing::warn!("HTTP {}: {} - {}: {}", $status, $error, $msg, $internal_msg);
-        $crate::types::errors::AppError::new(
-            $crate::types::errors::StatusKind::Http($status),
-            $error,
-            $msg,
-        )
+    (custom($n:expr) $(@ $level:ident)?, $error:expr, $msg:expr, $internal_msg:expr) => {{
+        $crate::app_error!(@log [$($level)? warn], "App Status {}: {} - {}: {}", $n, $error, $msg, $internal_msg);
+        $crate::errors::AppError::new($crate::errors::StatusKind::App($n), $error, $msg)
+    }};
+
+    (http($status:expr) $(@ $level:ident)?, $error:expr, $msg:expr) => {{
+        $crate::app_error!(@log [$($level)? warn], "HTTP {}: {} - {}", $status, $error, $msg);
+        $crate::errors::AppError::new($crate::errors::StatusKind::Http($status), $error, $msg)
+    }};
+
+    (http($status:expr) $(@ $level:ident)?, $error:expr, $msg:expr, $internal_msg:expr) => {{
+        $crate::app_error!(@log [$($level)? warn], "HTTP {}: {} - {}: {}", $status, $error, $msg, $internal_msg);
+        $crate::errors::AppError::new($crate::errors::StatusKind::Http($status), $error, $msg)
+    }};
+
+    (kind($kind:expr) $(@ $level:ident)?, $error:expr, $msg:expr) => {{
+        $crate::app_error!(@log [$($level)? warn], "Status {:?}: {} - {}", $kind, $error, $msg);
+        $crate::errors::AppError::new($kind, $error, $msg)
+    }};
+
+    (kind($kind:expr) $(@ $level:ident)?, $error:expr, $msg:expr, $internal_msg:expr) => {{
+        $crate::app_error!(@log [$($level)? warn], "Status {:?}: {} - {}: {}", $kind, $error, $msg, $internal_msg);
+        $crate::errors::AppError::new($kind, $error, $msg)
     }};
 
     // Matches AppError::bad_request
-    (bad_request, $error:expr, $msg:expr $(, $internal_msg:expr)?) => {{
-        tracing::warn!("Bad Request: {} - {}: {}", $error, $msg, $internal_msg);
-        $crate::types::errors::AppError::bad_request($error, $msg)
+    (bad_request $(@ $level:ident)?, $error:expr, $msg:expr) => {{
+        $crate::app_error!(@log [$($level)? warn], "Bad Request: {} - {}", $error, $msg);
+        $crate::errors::AppError::bad_request($error, $msg)
+    }};
+
+    (bad_request $(@ $level:ident)?, $error:expr, $msg:expr, $internal_msg:expr) => {{
+        $crate::app_error!(@log [$($level)? warn], "Bad Request: {} - {}: {}", $error, $msg, $internal_msg);
+        $crate::errors::AppError::bad_request($error, $msg)
     }};
 
     // Matches AppError::not_found
-    (not_found, $error:expr, $msg:expr $(, $internal_msg:expr)?) => {{
-        tracing::warn!("Not Found: {} - {}: {}", $error, $msg, $internal_msg);
-        $crate::types::errors::AppError::not_found($error, $msg)
+    (not_found $(@ $level:ident)?, $error:expr, $msg:expr) => {{
+        $crate::app_error!(@log [$($level)? warn], "Not Found: {} - {}", $error, $msg);
+        $crate::errors::AppError::not_found($error, $msg)
+    }};
+
+    (not_found $(@ $level:ident)?, $error:expr, $msg:expr, $internal_msg:expr) => {{
+        $crate::app_error!(@log [$($level)? warn], "Not Found: {} - {}: {}", $error, $msg, $internal_msg);
+        $crate::errors::AppError::not_found($error, $msg)
+    }};
+
+    // Matches AppError::gone
+    (gone $(@ $level:ident)?, $error:expr, $msg:expr) => {{
+        $crate::app_error!(@log [$($level)? warn], "Gone: {} - {}", $error, $msg);
+        $crate::errors::AppError::gone($error, $msg)
+    }};
+
+    (gone $(@ $level:ident)?, $error:expr, $msg:expr, $internal_msg:expr) => {{
+        $crate::app_error!(@log [$($level)? warn], "Gone: {} - {}: {}", $error, $msg, $internal_msg);
+        $crate::errors::AppError::gone($error, $msg)
     }};
 
     // Matches AppError::unauthorized
-    (unauthorized, $msg:expr $(, $internal_msg:expr)?) => {{
-        tracing::warn!("Unauthorized: {}: {}", $msg, $internal_msg);
-        $crate::types::errors::AppError::unauthorized($msg)
+    (unauthorized $(@ $level:ident)?, $msg:expr) => {{
+        $crate::app_error!(@log [$($level)? warn], "Unauthorized: {}", $msg);
+        $crate::errors::AppError::unauthorized($msg)
+    }};
+
+    (unauthorized $(@ $level:ident)?, $msg:expr, $internal_msg:expr) => {{
+        $crate::app_error!(@log [$($level)? warn], "Unauthorized: {}: {}", $msg, $internal_msg);
+        $crate::errors::AppError::unauthorized($msg)
+    }};
+
+    (unauthorized $(@ $level:ident)?, $msg:expr; redacted: $secret:expr) => {{
+        $crate::app_error!(@log [$($level)? warn], "Unauthorized: {}: {}", $msg, $crate::errors::redact(&$secret));
+        $crate::errors::AppError::unauthorized($msg)
+    }};
+
+    // Matches AppError::unprocessable_entity
+    (unprocessable_entity $(@ $level:ident)?, $error:expr, $msg:expr) => {{
+        $crate::app_error!(@log [$($level)? warn], "Unprocessable Entity: {} - {}", $error, $msg);
+        $crate::errors::AppError::unprocessable_entity($error, $msg)
+    }};
+
+    (unprocessable_entity $(@ $level:ident)?, $error:expr, $msg:expr, $internal_msg:expr) => {{
+        $crate::app_error!(@log [$($level)? warn], "Unprocessable Entity: {} - {}: {}", $error, $msg, $internal_msg);
+        $crate::errors::AppError::unprocessable_entity($error, $msg)
+    }};
+
+    // Matches AppError::forbidden
+    (forbidden $(@ $level:ident)?, $msg:expr) => {{
+        $crate::app_error!(@log [$($level)? warn], "Forbidden: {}", $msg);
+        $crate::errors::AppError::forbidden($msg)
+    }};
+
+    (forbidden $(@ $level:ident)?, $msg:expr, $internal_msg:expr) => {{
+        $crate::app_error!(@log [$($level)? warn], "Forbidden: {}: {}", $msg, $internal_msg);
+        $crate::errors::AppError::forbidden($msg)
     }};
 
     // Matches AppError::conflict
-    (conflict, $error:expr, $msg:expr $(, $internal_msg:expr)?) => {{
-        tracing::warn!("Conflict: {} - {}: {}", $error, $msg, $internal_msg);
-        $crate::types::errors::AppError::conflict($error, $msg)
+    (conflict $(@ $level:ident)?, $error:expr, $msg:expr) => {{
+        $crate::app_error!(@log [$($level)? warn], "Conflict: {} - {}", $error, $msg);
+        $crate::errors::AppError::conflict($error, $msg)
+    }};
+
+    (conflict $(@ $level:ident)?, $error:expr, $msg:expr, $internal_msg:expr) => {{
+        $crate::app_error!(@log [$($level)? warn], "Conflict: {} - {}: {}", $error, $msg, $internal_msg);
+        $crate::errors::AppError::conflict($error, $msg)
+    }};
+
+    // Matches AppError::precondition_failed
+    (precondition_failed $(@ $level:ident)?, $error:expr, $msg:expr) => {{
+        $crate::app_error!(@log [$($level)? warn], "Precondition Failed: {} - {}", $error, $msg);
+        $crate::errors::AppError::precondition_failed($error, $msg)
+    }};
+
+    (precondition_failed $(@ $level:ident)?, $error:expr, $msg:expr, $internal_msg:expr) => {{
+        $crate::app_error!(@log [$($level)? warn], "Precondition Failed: {} - {}: {}", $error, $msg, $internal_msg);
+        $crate::errors::AppError::precondition_failed($error, $msg)
+    }};
+
+    // Matches AppError::locked
+    (locked $(@ $level:ident)?, $msg:expr) => {{
+        $crate::app_error!(@log [$($level)? warn], "Locked: {}", $msg);
+        $crate::errors::AppError::locked($msg)
+    }};
+
+    (locked $(@ $level:ident)?, $msg:expr, $internal_msg:expr) => {{
+        $crate::app_error!(@log [$($level)? warn], "Locked: {}: {}", $msg, $internal_msg);
+        $crate::errors::AppError::locked($msg)
+    }};
+
+    // Matches AppError::payload_too_large
+    (payload_too_large $(@ $level:ident)?, $limit:expr) => {{
+        $crate::app_error!(@log [$($level)? warn], "Payload Too Large: {} bytes", $limit);
+        $crate::errors::AppError::payload_too_large($limit)
+    }};
+
+    (payload_too_large $(@ $level:ident)?, $limit:expr, $internal_msg:expr) => {{
+        $crate::app_error!(@log [$($level)? warn], "Payload Too Large: {} bytes: {}", $limit, $internal_msg);
+        $crate::errors::AppError::payload_too_large($limit)
+    }};
+
+    // Matches AppError::not_implemented
+    (not_implemented $(@ $level:ident)?, $msg:expr) => {{
+        $crate::app_error!(@log [$($level)? error], "Not Implemented: {}", $msg);
+        $crate::errors::AppError::not_implemented($msg)
+    }};
+
+    (not_implemented $(@ $level:ident)?, $msg:expr, $internal_msg:expr) => {{
+        $crate::app_error!(@log [$($level)? error], "Not Implemented: {}: {}", $msg, $internal_msg);
+        $crate::errors::AppError::not_implemented($msg)
+    }};
+
+    // Matches AppError::bad_gateway
+    (bad_gateway $(@ $level:ident)?, $msg:expr) => {{
+        $crate::app_error!(@log [$($level)? error], "Bad Gateway: {}", $msg);
+        $crate::errors::AppError::bad_gateway($msg)
+    }};
+
+    (bad_gateway $(@ $level:ident)?, $msg:expr, $internal_msg:expr) => {{
+        $crate::app_error!(@log [$($level)? error], "Bad Gateway: {}: {}", $msg, $internal_msg);
+        $crate::errors::AppError::bad_gateway($msg)
+    }};
+
+    // Matches AppError::service_unavailable
+    (service_unavailable $(@ $level:ident)?, $msg:expr) => {{
+        $crate::app_error!(@log [$($level)? error], "Service Unavailable: {}", $msg);
+        $crate::errors::AppError::service_unavailable($msg)
+    }};
+
+    (service_unavailable $(@ $level:ident)?, $msg:expr, $internal_msg:expr) => {{
+        $crate::app_error!(@log [$($level)? error], "Service Unavailable: {}: {}", $msg, $internal_msg);
+        $crate::errors::AppError::service_unavailable($msg)
     }};
 
     // Matches AppError::internal_server_error
-    (internal_server_error, $msg:expr $(, $internal_msg:expr)?) => {{
-        tracing::error!("Internal Server Error: {}: {}", $msg, $internal_msg);
-        $crate::types::errors::AppError::internal_server_error($msg)
+    (internal_server_error $(@ $level:ident)?, $msg:expr) => {{
+        $crate::app_error!(@log [$($level)? error], "Internal Server Error: {}", $msg);
+        $crate::errors::AppError::internal_server_error($msg)
     }};
+
+    (internal_server_error $(@ $level:ident)?, $msg:expr, $internal_msg:expr) => {{
+        $crate::app_error!(@log [$($level)? error], "Internal Server Error: {}: {}", $msg, $internal_msg);
+        $crate::errors::AppError::internal_server_error($msg)
+    }};
+
+    (@log [$level:ident $($_default:ident)?], $fmt:literal $(, $arg:expr)*) => {
+        $crate::__private::tracing::$level!($fmt $(, $arg)*)
+    };
 }
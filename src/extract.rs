@@ -0,0 +1,97 @@
+use axum::{
+    Json,
+    extract::{FromRequest, FromRequestParts, Request},
+};
+use http::{HeaderValue, header, request::Parts};
+use serde::de::DeserializeOwned;
+
+use crate::errors::AppError;
+
+/// Payload validation run by the [`Valid`] extractor after deserialization.
+pub trait Validate {
+    fn validate(&self) -> Result<(), AppError>;
+}
+
+/// JSON extractor that deserializes like [`Json`] and then runs [`Validate::validate`].
+///
+/// Bodies [`Json`] rejects become an `INVALID_JSON` error carrying the
+/// rejection's status (`400`, `415` for a missing JSON `Content-Type`, `413` for
+/// an oversized body, ...); validation failures are returned as-is.
+///
+/// # Example
+///
+/// ```rust
+/// use morde_rs::errors::AppError;
+/// use morde_rs::extract::{Valid, Validate};
+///
+/// #[derive(serde::Deserialize)]
+/// struct CreateUser {
+///     name: Option<String>,
+/// }
+///
+/// impl Validate for CreateUser {
+///     fn validate(&self) -> Result<(), AppError> {
+///         morde_rs::require_fields!(self, [name])
+///     }
+/// }
+///
+/// async fn create_user(Valid(payload): Valid<CreateUser>) -> String {
+///     payload.name.unwrap_or_default()
+/// }
+///
+/// let app: axum::Router = axum::Router::new().route("/users", axum::routing::post(create_user));
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Valid<T>(pub T);
+
+impl<T, S> FromRequest<S> for Valid<T>
+where
+    T: DeserializeOwned + Validate,
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let Json(value) = Json::<T>::from_request(req, state)
+            .await
+            .map_err(|rejection| {
+                AppError::new(rejection.status().into(), "INVALID_JSON", rejection.body_text())
+            })?;
+
+        value.validate()?;
+
+        Ok(Valid(value))
+    }
+}
+
+/// Extracts the token from an `Authorization: Bearer <token>` header.
+///
+/// A missing, non-Bearer, or empty header is rejected with a `401` carrying the
+/// `MISSING_TOKEN` code.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BearerToken(pub String);
+
+impl<S> FromRequestParts<S> for BearerToken
+where
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        parts
+            .headers
+            .get(header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.split_once(' '))
+            // Auth schemes are case-insensitive (RFC 9110 §11.1).
+            .filter(|(scheme, _)| scheme.eq_ignore_ascii_case("Bearer"))
+            .map(|(_, token)| token.trim())
+            .filter(|token| !token.is_empty())
+            .map(|token| BearerToken(token.to_string()))
+            .ok_or_else(|| {
+                AppError::unauthorized("Missing or malformed bearer token")
+                    .with_code("MISSING_TOKEN")
+                    .with_header(header::WWW_AUTHENTICATE, HeaderValue::from_static("Bearer"))
+            })
+    }
+}
@@ -0,0 +1,25 @@
+use subtle::ConstantTimeEq;
+
+/// Compare two tokens (a `jti`, an opaque API key) without leaking where they
+/// differ through timing.
+///
+/// Inputs of different lengths still run in constant time over the shorter
+/// one before comparing unequal, so only the length difference is observable.
+///
+/// # Example
+///
+/// ```rust
+/// use morde_rs::jwt::secure_token_eq;
+///
+/// assert!(secure_token_eq("jti-42", "jti-42"));
+/// assert!(!secure_token_eq("jti-42", "jti-43"));
+/// ```
+pub fn secure_token_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    let len = a.len().min(b.len());
+
+    let same_prefix = a[..len].ct_eq(&b[..len]);
+    let same_len = (a.len() as u64).ct_eq(&(b.len() as u64));
+
+    (same_prefix & same_len).into()
+}
@@ -1,11 +1,184 @@
 pub mod errors;
+#[cfg(feature = "axum")]
+pub mod extract;
+#[cfg(feature = "jwt")]
+pub mod jwt;
+pub mod pagination;
+#[cfg(feature = "time")]
+pub mod rfc3339;
+
+/// Common imports in one line.
+///
+/// ```rust
+/// use morde_rs::prelude::*;
+///
+/// struct Payload {
+///     name: Option<String>,
+/// }
+///
+/// fn handler(payload: Payload) -> Result<()> {
+///     if !check_empty_fields!(payload, [name]).is_empty() {
+///         return Err(app_error!(bad_request, "MISSING_NAME", "name is required"));
+///     }
+///     Ok(())
+/// }
+///
+/// let err: AppError = handler(Payload { name: None }).unwrap_err();
+/// assert_eq!(err.status, StatusKind::Http(http::StatusCode::BAD_REQUEST));
+/// ```
+pub mod prelude {
+    pub use crate::errors::{AppError, Result, StatusKind};
+    pub use crate::{app_error, check_empty_fields};
+}
+
+#[doc(hidden)]
+pub mod __private {
+    //! Support items for the exported macros. Not part of the public API.
+
+    pub use serde_json;
+    pub use tracing;
+
+    use std::any::Any;
+    use std::borrow::Cow;
+    use std::collections::{HashMap, HashSet};
+    use std::ops::{Bound, RangeBounds};
+
+    /// Steps through an `Option` field, yielding its inner value when present.
+    pub trait NestedOption<'a> {
+        type Inner: 'a;
+        fn nested(self) -> Option<&'a Self::Inner>;
+    }
+
+    impl<'a, T> NestedOption<'a> for &'a Option<T> {
+        type Inner = T;
+        fn nested(self) -> Option<&'a T> {
+            self.as_ref()
+        }
+    }
+
+    /// Fallback for non-`Option` fields, picked up through autoref when
+    /// [`NestedOption`] does not apply.
+    pub trait NestedValue<'a> {
+        type Inner: 'a;
+        fn nested(&self) -> Option<&'a Self::Inner>;
+    }
+
+    impl<'a, T> NestedValue<'a> for &'a T {
+        type Inner = T;
+        fn nested(&self) -> Option<&'a T> {
+            Some(*self)
+        }
+    }
+
+    /// The string behind a `String`, `Cow<'static, str>` or `&'static str`.
+    fn as_str(value: &dyn Any) -> Option<&str> {
+        if let Some(s) = value.downcast_ref::<String>() {
+            Some(s)
+        } else if let Some(s) = value.downcast_ref::<Cow<'static, str>>() {
+            Some(s)
+        } else {
+            value.downcast_ref::<&'static str>().copied()
+        }
+    }
+
+    pub fn is_empty_string(value: &dyn Any) -> bool {
+        as_str(value).is_some_and(str::is_empty)
+    }
+
+    pub fn is_blank_string(value: &dyn Any) -> bool {
+        as_str(value).is_some_and(|s| s.trim().is_empty())
+    }
+
+    /// Empty `String`s and empty collections of the types listed on
+    /// [`check_empty_fields!`](crate::check_empty_fields).
+    pub fn is_empty_collection(value: &dyn Any) -> bool {
+        macro_rules! empty_if {
+            ($($ty:ty),*) => {
+                $(
+                    if let Some(v) = value.downcast_ref::<$ty>() {
+                        return v.is_empty();
+                    }
+                )*
+            };
+        }
+
+        if let Some(s) = as_str(value) {
+            return s.is_empty();
+        }
+
+        empty_if!(
+            Vec<String>,
+            Vec<i32>,
+            Vec<i64>,
+            Vec<u32>,
+            Vec<u64>,
+            Vec<f64>,
+            Vec<bool>,
+            Vec<serde_json::Value>,
+            HashSet<String>,
+            HashMap<String, String>,
+            HashMap<String, serde_json::Value>
+        );
+
+        false
+    }
+
+    /// Why a `String` falls outside `bounds` (measured in chars), or `None` when
+    /// it fits or is not a `String`.
+    pub fn bounds_violation(
+        value: &dyn Any,
+        bounds: impl RangeBounds<usize>,
+    ) -> Option<&'static str> {
+        let len = value.downcast_ref::<String>()?.chars().count();
+
+        let too_long = match bounds.end_bound() {
+            Bound::Included(&end) => len > end,
+            Bound::Excluded(&end) => len >= end,
+            Bound::Unbounded => false,
+        };
+
+        if too_long {
+            Some("too_long")
+        } else if bounds.contains(&len) {
+            None
+        } else if len == 0 {
+            Some("empty")
+        } else {
+            Some("too_short")
+        }
+    }
+}
 
 /// Check a payload for missing or empty string fields.
 ///
 /// Returns a `Vec<&'static str>` with the names of fields that are missing or
-/// contain an empty `String`. The macro checks `payload.field.as_ref()` and
-/// attempts to downcast to `String`, so it is most useful with `Option<String>`
-/// fields.
+/// contain an empty string. The macro steps into `payload.field` when it is an
+/// `Option` and attempts to downcast the value to `String`, `Cow<'static, str>`
+/// or `&'static str`, so it is most useful with `Option<String>` fields.
+///
+/// Fields may be dotted paths such as `address.street`; each segment may be an
+/// `Option` or a plain value, and a `None` anywhere along the path reports the
+/// full path as missing.
+///
+/// Passing a trailing `trim` flag treats whitespace-only strings as empty too.
+///
+/// A field may be followed by `=> predicate` to decide emptiness itself: the
+/// predicate receives the `&String` value and returns `true` when the field
+/// should be reported. Fields without a predicate use the mode's default check.
+///
+/// Passing a trailing `collections` flag also reports empty collections. Since
+/// the check relies on `Any` downcasting, only these concrete types are
+/// recognized: `Vec<String>`, `Vec<i32>`, `Vec<i64>`, `Vec<u32>`, `Vec<u64>`,
+/// `Vec<f64>`, `Vec<bool>`, `Vec<serde_json::Value>`, `HashSet<String>`,
+/// `HashMap<String, String>` and `HashMap<String, serde_json::Value>`.
+///
+/// Downcasting needs `'static` types, so borrowed fields such as `Option<&'a str>`
+/// or `Option<Cow<'a, str>>` need the trailing `str` flag instead. It checks any
+/// `AsRef<str>` value without downcasting, but does not accept predicates.
+///
+/// Prefixing the call with `into missing,` pushes the field names onto an
+/// existing `Vec<&'static str>` (or `&mut Vec`) instead of returning a new one,
+/// so several payloads can be checked into a single list.
 ///
 /// # Example
 ///
@@ -18,23 +191,393 @@ pub mod errors;
 /// let payload = Payload { name: Some("".to_string()), email: None };
 /// let missing = morde_rs::check_empty_fields!(payload, [name, email]);
 /// assert_eq!(missing, vec!["name", "email"]);
+///
+/// let payload = Payload { name: Some("  ".to_string()), email: Some("a@b.c".to_string()) };
+/// let missing = morde_rs::check_empty_fields!(payload, [name, email], trim);
+/// assert_eq!(missing, vec!["name"]);
 /// ```
 #[macro_export]
 macro_rules! check_empty_fields {
-    ($payload:expr, [$($field:ident),*]) => {
+    (into $missing:expr, $payload:expr, [$($head:ident $(. $rest:ident)* $(=> $pred:expr)?),*]) => {
+        $crate::check_empty_fields!(
+            @push $missing,
+            $payload,
+            [$($head $(. $rest)* $(=> $pred)?),*],
+            $crate::__private::is_empty_string
+        )
+    };
+
+    (into $missing:expr, $payload:expr, [$($head:ident $(. $rest:ident)* $(=> $pred:expr)?),*], trim) => {
+        $crate::check_empty_fields!(
+            @push $missing,
+            $payload,
+            [$($head $(. $rest)* $(=> $pred)?),*],
+            $crate::__private::is_blank_string
+        )
+    };
+
+    (into $missing:expr, $payload:expr, [$($head:ident $(. $rest:ident)* $(=> $pred:expr)?),*], collections) => {
+        $crate::check_empty_fields!(
+            @push $missing,
+            $payload,
+            [$($head $(. $rest)* $(=> $pred)?),*],
+            $crate::__private::is_empty_collection
+        )
+    };
+
+    ($payload:expr, [$($head:ident $(. $rest:ident)* $(=> $pred:expr)?),*]) => {
+        $crate::check_empty_fields!(
+            @collect $payload,
+            [$($head $(. $rest)* $(=> $pred)?),*],
+            $crate::__private::is_empty_string
+        )
+    };
+
+    ($payload:expr, [$($head:ident $(. $rest:ident)* $(=> $pred:expr)?),*], trim) => {
+        $crate::check_empty_fields!(
+            @collect $payload,
+            [$($head $(. $rest)* $(=> $pred)?),*],
+            $crate::__private::is_blank_string
+        )
+    };
+
+    ($payload:expr, [$($head:ident $(. $rest:ident)* $(=> $pred:expr)?),*], collections) => {
+        $crate::check_empty_fields!(
+            @collect $payload,
+            [$($head $(. $rest)* $(=> $pred)?),*],
+            $crate::__private::is_empty_collection
+        )
+    };
+
+    ($payload:expr, [$($head:ident $(. $rest:ident)*),*], str) => {
         {
+            #[allow(unused_imports)]
+            use $crate::__private::{NestedOption as _, NestedValue as _};
+
             let mut missing = Vec::new();
             $(
-                if $payload.$field.as_ref().map(|v| {
-                    (v as &dyn ::std::any::Any)
-                        .downcast_ref::<String>()
-                        .map(|s| s.is_empty())
-                        .unwrap_or(false)
-                }).unwrap_or(true) {
-                    missing.push(stringify!($field));
+                let field = &$payload.$head;
+                if field
+                    .nested()
+                    $(.and_then(|v| (&v.$rest).nested()))*
+                    .map(|v| ::std::convert::AsRef::<str>::as_ref(v).is_empty())
+                    .unwrap_or(true)
+                {
+                    missing.push(stringify!($head $(. $rest)*));
                 }
             )*
             missing
         }
     };
+
+    (@collect $payload:expr, [$($head:ident $(. $rest:ident)* $(=> $pred:expr)?),*], $is_empty:path) => {
+        {
+            let mut missing = Vec::new();
+            $crate::check_empty_fields!(
+                @push missing,
+                $payload,
+                [$($head $(. $rest)* $(=> $pred)?),*],
+                $is_empty
+            );
+            missing
+        }
+    };
+
+    (@push $missing:expr, $payload:expr, [$($head:ident $(. $rest:ident)* $(=> $pred:expr)?),*], $is_empty:path) => {
+        {
+            #[allow(unused_imports)]
+            use $crate::__private::{NestedOption as _, NestedValue as _};
+
+            $(
+                // Bind the field once so a typo is reported a single time at the call site.
+                let field = &$payload.$head;
+                if field
+                    .nested()
+                    $(.and_then(|v| (&v.$rest).nested()))*
+                    .map(|v| {
+                        $crate::check_empty_fields!(@is_empty $is_empty $(, $pred)?)(
+                            v as &dyn ::std::any::Any,
+                        )
+                    })
+                    .unwrap_or(true)
+                {
+                    $missing.push(stringify!($head $(. $rest)*));
+                }
+            )*
+        }
+    };
+
+    (@is_empty $is_empty:path) => {
+        $is_empty
+    };
+
+    (@is_empty $is_empty:path, $pred:expr) => {
+        |v: &dyn ::std::any::Any| v.downcast_ref::<String>().is_some_and($pred)
+    };
+}
+
+/// Check whether any field is missing or empty without collecting their names.
+///
+/// Applies the same default check as [`check_empty_fields!`] but returns a
+/// `bool`, stopping at the first empty field and never allocating.
+///
+/// # Example
+///
+/// ```rust
+/// struct Payload {
+///     name: Option<String>,
+///     email: Option<String>,
+/// }
+///
+/// let payload = Payload { name: Some("bob".to_string()), email: None };
+/// assert!(morde_rs::has_empty_fields!(payload, [name, email]));
+/// assert!(!morde_rs::has_empty_fields!(payload, [name]));
+/// ```
+#[macro_export]
+macro_rules! has_empty_fields {
+    ($payload:expr, [$($head:ident $(. $rest:ident)*),*]) => {
+        {
+            #[allow(unused_imports)]
+            use $crate::__private::{NestedOption as _, NestedValue as _};
+
+            false
+            $(
+                || Some(&$payload)
+                    .and_then(|v| (&v.$head).nested())
+                    $(.and_then(|v| (&v.$rest).nested()))*
+                    .map(|v| $crate::__private::is_empty_string(v as &dyn ::std::any::Any))
+                    .unwrap_or(true)
+            )*
+        }
+    };
+}
+
+/// Check string fields against a length range in one pass.
+///
+/// Each field is paired with a range of allowed lengths, counted in chars, and
+/// the macro returns a `Vec<(&'static str, &'static str)>` of `(field, reason)`
+/// pairs. The reason is `"too_long"` past the upper bound, `"too_short"` for a
+/// non-empty value under the lower bound, and `"empty"` when the field is
+/// missing, or empty while the range requires at least one char. Fields that
+/// are not `String`s are skipped. Dotted paths and `Option` fields are walked like
+/// in [`check_empty_fields!`].
+///
+/// # Example
+///
+/// ```rust
+/// struct Payload {
+///     name: Option<String>,
+///     bio: String,
+/// }
+///
+/// let payload = Payload { name: None, bio: "x".repeat(300) };
+/// let violations = morde_rs::check_field_bounds!(payload, [name: 1..=64, bio: 0..=280]);
+/// assert_eq!(violations, vec![("name", "empty"), ("bio", "too_long")]);
+/// ```
+#[macro_export]
+macro_rules! check_field_bounds {
+    ($payload:expr, [$($head:ident $(. $rest:ident)* : $bounds:expr),*]) => {
+        {
+            #[allow(unused_imports)]
+            use $crate::__private::{NestedOption as _, NestedValue as _};
+
+            let mut violations: Vec<(&'static str, &'static str)> = Vec::new();
+            $(
+                let reason = match Some(&$payload)
+                    .and_then(|v| (&v.$head).nested())
+                    $(.and_then(|v| (&v.$rest).nested()))*
+                {
+                    Some(v) => $crate::__private::bounds_violation(v as &dyn ::std::any::Any, $bounds),
+                    None => Some("empty"),
+                };
+                if let Some(reason) = reason {
+                    violations.push((stringify!($head $(. $rest)*), reason));
+                }
+            )*
+            violations
+        }
+    };
+}
+
+/// Runtime counterpart to [`check_empty_fields!`] for field lists only known at runtime.
+///
+/// Reports each top-level key of `payload` that is missing, `null`, or an empty
+/// string. Keys are matched literally, so `"a.b"` names a single key.
+///
+/// # Example
+///
+/// ```rust
+/// let payload = serde_json::json!({ "name": "", "email": null, "age": 30 });
+/// let missing = morde_rs::check_empty_fields_dyn(&payload, &["name", "email", "age", "phone"]);
+/// assert_eq!(missing, vec!["name", "email", "phone"]);
+/// ```
+pub fn check_empty_fields_dyn(payload: &serde_json::Value, fields: &[&str]) -> Vec<String> {
+    fields
+        .iter()
+        .filter(|field| {
+            match payload.get(field) {
+                None | Some(serde_json::Value::Null) => true,
+                Some(serde_json::Value::String(s)) => s.is_empty(),
+                Some(_) => false,
+            }
+        })
+        .map(|field| field.to_string())
+        .collect()
+}
+
+/// Check a payload for blank string fields that are not wrapped in `Option`.
+///
+/// Works with any field implementing `AsRef<str>` (`String`, `&str`, `Cow<str>`)
+/// and reports it when `trim()` leaves nothing. Use [`check_empty_fields!`] for
+/// `Option`-wrapped fields.
+///
+/// # Example
+///
+/// ```rust
+/// struct Payload<'a> {
+///     name: String,
+///     email: &'a str,
+/// }
+///
+/// let payload = Payload { name: "".to_string(), email: "bob@example.com" };
+/// let missing = morde_rs::check_empty_strings!(payload, [name, email]);
+/// assert_eq!(missing, vec!["name"]);
+/// ```
+#[macro_export]
+macro_rules! check_empty_strings {
+    ($payload:expr, [$($head:ident $(. $rest:ident)*),*]) => {
+        {
+            let mut missing = Vec::new();
+            $(
+                if ::std::convert::AsRef::<str>::as_ref(&$payload.$head $(. $rest)*)
+                    .trim()
+                    .is_empty()
+                {
+                    missing.push(stringify!($head $(. $rest)*));
+                }
+            )*
+            missing
+        }
+    };
+}
+
+/// Check a payload for missing or empty string fields, returning an error if any are found.
+///
+/// Runs [`check_empty_fields!`] and, when fields are missing, returns
+/// `Err(AppError::bad_request("MISSING_FIELDS", ...))` with the offending field
+/// names listed under `details.fields`.
+///
+/// # Example
+///
+/// ```rust
+/// use morde_rs::errors::AppError;
+///
+/// struct Payload {
+///     name: Option<String>,
+///     email: Option<String>,
+/// }
+///
+/// fn handler(payload: Payload) -> Result<(), AppError> {
+///     morde_rs::require_fields!(payload, [name, email])?;
+///     Ok(())
+/// }
+///
+/// let err = handler(Payload { name: Some("bob".to_string()), email: None }).unwrap_err();
+/// assert_eq!(err.error, "MISSING_FIELDS");
+/// assert_eq!(err.extras.details, Some(serde_json::json!({ "fields": ["email"] })));
+/// ```
+#[macro_export]
+macro_rules! require_fields {
+    ($payload:expr, [$($head:ident $(. $rest:ident)*),*]) => {
+        {
+            let missing = $crate::check_empty_fields!($payload, [$($head $(. $rest)*),*]);
+            if missing.is_empty() {
+                Ok(())
+            } else {
+                Err($crate::errors::AppError::bad_request(
+                    "MISSING_FIELDS",
+                    format!("Missing required fields: {}", missing.join(", ")),
+                )
+                .with_details($crate::__private::serde_json::json!({ "fields": missing })))
+            }
+        }
+    };
+}
+
+/// Run [`check_empty_fields!`] and early-return a `400 MISSING_FIELDS` built
+/// and logged with [`app_error!`] when any field is missing.
+///
+/// Like `?`, the error is converted with `From` into the function's error type.
+///
+/// # Example
+///
+/// ```rust
+/// use morde_rs::errors::AppError;
+///
+/// struct Payload {
+///     name: Option<String>,
+///     email: Option<String>,
+/// }
+///
+/// fn handler(payload: Payload) -> Result<(), AppError> {
+///     morde_rs::validate_fields!(payload, [name, email]);
+///     Ok(())
+/// }
+///
+/// let valid = Payload { name: Some("bob".to_string()), email: Some("bob@example.com".to_string()) };
+/// assert!(handler(valid).is_ok());
+///
+/// let invalid = Payload { name: Some("bob".to_string()), email: None };
+/// let err = handler(invalid).unwrap_err();
+/// assert_eq!(err.error, "MISSING_FIELDS");
+/// assert_eq!(err.message, r#"missing: ["email"]"#);
+/// ```
+#[macro_export]
+macro_rules! validate_fields {
+    ($payload:expr, [$($head:ident $(. $rest:ident)*),*]) => {
+        let missing = $crate::check_empty_fields!($payload, [$($head $(. $rest)*),*]);
+        if !missing.is_empty() {
+            return Err(::std::convert::From::from($crate::app_error!(
+                bad_request,
+                "MISSING_FIELDS",
+                format!("missing: {:?}", missing)
+            )));
+        }
+    };
+}
+
+/// Unwrap an `Option`, or early-return a `404` built with [`AppError::not_found`](errors::AppError::not_found).
+///
+/// The error is converted with `From`, like `?`, so the enclosing function may
+/// return any error type that `AppError` converts into.
+///
+/// # Example
+///
+/// ```rust
+/// use morde_rs::errors::AppError;
+///
+/// fn find_user(id: u32) -> Option<String> {
+///     (id == 1).then(|| "alice".to_string())
+/// }
+///
+/// fn handler(id: u32) -> Result<String, AppError> {
+///     let user = morde_rs::ok_or_404!(find_user(id), "USER_NOT_FOUND", "user not found");
+///     Ok(user)
+/// }
+///
+/// assert_eq!(handler(1).unwrap(), "alice");
+/// assert_eq!(handler(2).unwrap_err().error, "USER_NOT_FOUND");
+/// ```
+#[macro_export]
+macro_rules! ok_or_404 {
+    ($opt:expr, $error:expr, $msg:expr) => {
+        match $opt {
+            Some(value) => value,
+            None => {
+                return Err(::std::convert::From::from(
+                    $crate::errors::AppError::not_found($error, $msg),
+                ));
+            }
+        }
+    };
 }
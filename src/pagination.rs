@@ -0,0 +1,25 @@
+use crate::errors::AppError;
+
+/// Validate `page`/`limit` query parameters for list endpoints.
+///
+/// Fails with `400 INVALID_PAGINATION` when `limit` is zero or exceeds `max_limit`.
+/// Any page is accepted, since a page past the end is simply empty.
+///
+/// # Example
+///
+/// ```rust
+/// use morde_rs::pagination::validate_page;
+///
+/// assert!(validate_page(1, 20, 100).is_ok());
+/// assert_eq!(validate_page(1, 500, 100).unwrap_err().error, "INVALID_PAGINATION");
+/// ```
+pub fn validate_page(_page: u32, limit: u32, max_limit: u32) -> Result<(), AppError> {
+    if limit == 0 || limit > max_limit {
+        return Err(AppError::bad_request(
+            "INVALID_PAGINATION",
+            format!("limit must be between 1 and {max_limit}"),
+        ));
+    }
+
+    Ok(())
+}
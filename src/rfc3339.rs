@@ -0,0 +1,47 @@
+//! Serde helpers that store Unix timestamps (e.g. JWT `exp`/`nbf`/`iat`) as
+//! `u64` seconds while exchanging them as RFC 3339 strings.
+//!
+//! Use the functions with `serialize_with`/`deserialize_with`, or the whole
+//! module with `#[serde(with = "morde_rs::rfc3339")]`.
+//!
+//! # Example
+//!
+//! ```rust
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Claims {
+//!     #[serde(with = "morde_rs::rfc3339")]
+//!     exp: u64,
+//! }
+//!
+//! let json = serde_json::to_string(&Claims { exp: 1_700_000_000 }).unwrap();
+//! assert_eq!(json, r#"{"exp":"2023-11-14T22:13:20Z"}"#);
+//! ```
+
+use serde::{Deserialize, Deserializer, Serializer, de, ser};
+use time::OffsetDateTime;
+use time::format_description::well_known::Rfc3339;
+
+pub use deserialize_rfc3339_as_unix as deserialize;
+pub use serialize_unix_as_rfc3339 as serialize;
+
+pub fn serialize_unix_as_rfc3339<S: Serializer>(
+    secs: &u64,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let secs = i64::try_from(*secs).map_err(ser::Error::custom)?;
+    let formatted = OffsetDateTime::from_unix_timestamp(secs)
+        .map_err(ser::Error::custom)?
+        .format(&Rfc3339)
+        .map_err(ser::Error::custom)?;
+
+    serializer.serialize_str(&formatted)
+}
+
+pub fn deserialize_rfc3339_as_unix<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<u64, D::Error> {
+    let s = String::deserialize(deserializer)?;
+    let time = OffsetDateTime::parse(&s, &Rfc3339).map_err(de::Error::custom)?;
+
+    u64::try_from(time.unix_timestamp()).map_err(de::Error::custom)
+}
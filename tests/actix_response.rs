@@ -0,0 +1,18 @@
+#![cfg(feature = "actix")]
+
+use actix_web::{ResponseError, body::to_bytes, http::StatusCode};
+use morde_rs::errors::AppError;
+use serde_json::json;
+
+#[tokio::test]
+async fn response_error_uses_mapped_status_and_json_body() {
+    let err = AppError::not_found("USER", "user not found");
+    assert_eq!(err.status_code(), StatusCode::NOT_FOUND);
+
+    let response = err.error_response();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    let body = to_bytes(response.into_body()).await.unwrap();
+    let value: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(value, json!({ "error": "USER", "message": "user not found" }));
+}
@@ -0,0 +1,19 @@
+#![cfg(feature = "anyhow")]
+
+use http::StatusCode;
+use morde_rs::errors::{AppError, StatusKind};
+
+#[test]
+fn plain_anyhow_error_maps_to_500() {
+    let err = AppError::from(anyhow::anyhow!("something broke"));
+    assert_eq!(err.status, StatusKind::Http(StatusCode::INTERNAL_SERVER_ERROR));
+    assert_eq!(err.message, "something broke");
+}
+
+#[test]
+fn wrapped_app_error_passes_through() {
+    let wrapped = anyhow::Error::from(AppError::not_found("USER", "user not found"));
+    let err = AppError::from(wrapped);
+    assert_eq!(err.status, StatusKind::Http(StatusCode::NOT_FOUND));
+    assert_eq!(err.error, "USER");
+}
@@ -0,0 +1,33 @@
+#![cfg(feature = "axum")]
+
+use axum::response::IntoResponse;
+use http::StatusCode;
+use morde_rs::errors::{ApiResult, AppError};
+use serde_json::json;
+
+async fn body_json(response: axum::response::Response) -> serde_json::Value {
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    serde_json::from_slice(&body).unwrap()
+}
+
+#[tokio::test]
+async fn ok_is_serialized_as_200_json() {
+    let response = ApiResult(Ok(json!({ "id": 42 }))).into_response();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(body_json(response).await, json!({ "id": 42 }));
+}
+
+#[tokio::test]
+async fn err_uses_app_error_response() {
+    let result: Result<(), AppError> = Err(AppError::not_found("USER_NOT_FOUND", "no such user"));
+    let response = ApiResult::from(result).into_response();
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    assert_eq!(
+        body_json(response).await,
+        json!({ "error": "USER_NOT_FOUND", "message": "no such user" })
+    );
+}
@@ -0,0 +1,248 @@
+use http::StatusCode;
+use morde_rs::errors::{AppError, StatusKind};
+
+#[test]
+fn forbidden_maps_to_403() {
+    let err = AppError::forbidden("nope");
+    assert_eq!(err.status, StatusKind::Http(StatusCode::FORBIDDEN));
+    assert_eq!(err.status.to_http_status(), StatusCode::FORBIDDEN);
+    assert_eq!(err.error, "FORBIDDEN");
+    assert_eq!(err.message, "nope");
+}
+
+#[test]
+fn gone_maps_to_410() {
+    let err = AppError::gone("POST_DELETED", "this post was removed");
+    assert_eq!(err.status.to_http_status(), StatusCode::GONE);
+    assert_eq!(err.error, "POST_DELETED");
+}
+
+#[test]
+fn unprocessable_entity_maps_to_422() {
+    let err = AppError::unprocessable_entity("VALIDATION", "email is invalid");
+    assert_eq!(err.status.to_http_status(), StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(err.error, "VALIDATION");
+}
+
+#[test]
+fn service_unavailable_maps_to_503() {
+    let err = AppError::service_unavailable("down for maintenance");
+    assert_eq!(err.status.to_http_status(), StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(err.error, "SERVICE_UNAVAILABLE");
+}
+
+#[test]
+fn with_details_sets_details() {
+    let err = AppError::bad_request("VALIDATION", "invalid payload")
+        .with_details(serde_json::json!({ "email": "required" }));
+    assert_eq!(err.extras.details, Some(serde_json::json!({ "email": "required" })));
+
+    assert!(AppError::not_found("USER", "missing").extras.details.is_none());
+}
+
+#[test]
+fn serde_json_error_maps_to_400() {
+    #[derive(Debug, serde::Deserialize)]
+    #[allow(dead_code)]
+    struct Payload {
+        name: String,
+    }
+
+    fn parse(body: &str) -> morde_rs::errors::Result<Payload> {
+        Ok(serde_json::from_str::<Payload>(body)?)
+    }
+
+    let err = parse("{ not json").unwrap_err();
+    assert_eq!(err.status, StatusKind::Http(StatusCode::BAD_REQUEST));
+    assert_eq!(err.error, "INVALID_JSON");
+    assert!(!err.message.is_empty());
+}
+
+#[test]
+fn io_error_maps_by_kind() {
+    use std::io::{Error, ErrorKind};
+
+    let err = AppError::from(Error::new(ErrorKind::NotFound, "missing.txt"));
+    assert_eq!(err.status, StatusKind::Http(StatusCode::NOT_FOUND));
+    assert_eq!(err.error, "NOT_FOUND");
+
+    let err = AppError::from(Error::new(ErrorKind::PermissionDenied, "denied"));
+    assert_eq!(err.status, StatusKind::Http(StatusCode::FORBIDDEN));
+    assert_eq!(err.error, "FORBIDDEN");
+
+    let err = AppError::from(Error::other("boom"));
+    assert_eq!(err.status, StatusKind::Http(StatusCode::INTERNAL_SERVER_ERROR));
+}
+
+#[test]
+fn boxed_error_is_kept_as_source() {
+    use std::error::Error;
+
+    #[derive(Debug)]
+    struct PoolExhausted;
+
+    impl std::fmt::Display for PoolExhausted {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "connection pool exhausted")
+        }
+    }
+
+    impl Error for PoolExhausted {}
+
+    let boxed: Box<dyn Error + Send + Sync> = Box::new(PoolExhausted);
+    let err = AppError::from(boxed);
+
+    assert_eq!(err.status, StatusKind::Http(StatusCode::INTERNAL_SERVER_ERROR));
+    assert!(!err.message.contains("pool"));
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        "connection pool exhausted"
+    );
+}
+
+#[test]
+fn display_formats_error_and_message() {
+    let err = AppError::not_found("USER_NOT_FOUND", "no user with that id");
+    assert_eq!(err.to_string(), "USER_NOT_FOUND: no user with that id");
+}
+
+#[test]
+fn converts_into_boxed_std_error() {
+    fn fails() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        Err(AppError::conflict("DUPLICATE", "already exists"))?
+    }
+
+    let err = fails().unwrap_err();
+    assert_eq!(err.to_string(), "DUPLICATE: already exists");
+    assert!(err.downcast_ref::<AppError>().is_some());
+}
+
+#[test]
+fn method_not_allowed_records_allow_header() {
+    let err = AppError::method_not_allowed(&[http::Method::GET, http::Method::POST]);
+    assert_eq!(err.status.to_http_status(), StatusCode::METHOD_NOT_ALLOWED);
+    assert_eq!(err.error, "METHOD_NOT_ALLOWED");
+    assert_eq!(err.extras.headers, vec![(http::header::ALLOW, "GET, POST".parse().unwrap())]);
+}
+
+#[test]
+fn equality_compares_status_error_and_message() {
+    fn find_user(id: u32) -> morde_rs::errors::Result<&'static str> {
+        match id {
+            1 => Ok("alice"),
+            _ => Err(AppError::not_found("USER_NOT_FOUND", "no such user").with_trace_id("t-1")),
+        }
+    }
+
+    assert_eq!(
+        find_user(2).unwrap_err(),
+        AppError::not_found("USER_NOT_FOUND", "no such user")
+    );
+    assert_ne!(
+        AppError::not_found("USER_NOT_FOUND", "no such user"),
+        AppError::gone("USER_NOT_FOUND", "no such user")
+    );
+}
+
+#[test]
+fn with_status_replaces_status() {
+    let err = AppError::not_found("USER", "user not found").with_status(1001u16);
+    assert_eq!(err.status, StatusKind::App(1001));
+    assert_eq!(err.error, "USER");
+
+    let err = AppError::internal_server_error("db down").with_status(StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(err.status, StatusKind::Http(StatusCode::SERVICE_UNAVAILABLE));
+}
+
+#[test]
+fn payload_too_large_maps_to_413() {
+    let err = AppError::payload_too_large(1024);
+    assert_eq!(err.status.to_http_status(), StatusCode::PAYLOAD_TOO_LARGE);
+    assert_eq!(err.error, "PAYLOAD_TOO_LARGE");
+    assert_eq!(err.message, "request body exceeds 1024 bytes");
+    assert_eq!(err.extras.details, Some(serde_json::json!({ "limit_bytes": 1024 })));
+}
+
+#[test]
+fn not_implemented_maps_to_501() {
+    let err = AppError::not_implemented("coming soon");
+    assert_eq!(err.status.to_http_status(), StatusCode::NOT_IMPLEMENTED);
+    assert_eq!(err.error, "NOT_IMPLEMENTED");
+}
+
+#[test]
+fn clone_keeps_public_fields_and_drops_source() {
+    let source: Box<dyn std::error::Error + Send + Sync> = "disk on fire".into();
+    let err = AppError::from(source)
+        .with_code("E42")
+        .with_details(serde_json::json!({ "disk": "sda" }));
+    let cloned = err.clone();
+
+    assert_eq!(cloned, err);
+    assert_eq!(cloned.extras.code, err.extras.code);
+    assert_eq!(cloned.extras.details, err.extras.details);
+    assert!(err.extras.source.is_some());
+    assert!(cloned.extras.source.is_none());
+}
+
+#[test]
+fn bad_gateway_maps_to_502() {
+    let err = AppError::bad_gateway("upstream returned garbage");
+    assert_eq!(err.status.to_http_status(), StatusCode::BAD_GATEWAY);
+    assert_eq!(err.error, "BAD_GATEWAY");
+}
+
+#[test]
+fn from_status_derives_slug_from_reason() {
+    let err = AppError::from_status(StatusCode::NOT_FOUND, "missing");
+    assert_eq!(err.status, StatusKind::Http(StatusCode::NOT_FOUND));
+    assert_eq!(err.error, "NOT_FOUND");
+    assert_eq!(err.message, "missing");
+
+    let err = AppError::from_status(StatusCode::RANGE_NOT_SATISFIABLE, "bad range");
+    assert_eq!(err.error, "RANGE_NOT_SATISFIABLE");
+
+    let err = AppError::from_status(StatusCode::IM_A_TEAPOT, "short and stout");
+    assert_eq!(err.error, "IM_A_TEAPOT");
+
+    let err = AppError::from_status(StatusCode::from_u16(599).unwrap(), "odd");
+    assert_eq!(err.error, "UNKNOWN_ERROR");
+}
+
+#[test]
+fn client_and_server_errors_are_distinguished() {
+    let err = AppError::bad_request("INVALID", "bad input");
+    assert!(err.is_client_error());
+    assert!(!err.is_server_error());
+
+    let err = AppError::internal_server_error("boom");
+    assert!(err.is_server_error());
+    assert!(!err.is_client_error());
+}
+
+#[test]
+fn precondition_failed_maps_to_412() {
+    let err = AppError::precondition_failed("PRECONDITION_FAILED", "etag mismatch");
+    assert_eq!(err.status.to_http_status(), StatusCode::PRECONDITION_FAILED);
+    assert_eq!(err.error, "PRECONDITION_FAILED");
+}
+
+#[test]
+fn locked_maps_to_423() {
+    let err = AppError::locked("document is being edited");
+    assert_eq!(err.status.to_http_status(), StatusCode::LOCKED);
+    assert_eq!(err.error, "LOCKED");
+}
+
+#[test]
+fn context_prefixes_message() {
+    let err = AppError::not_found("USER_NOT_FOUND", "no such user").context("loading profile");
+    assert_eq!(err.message, "loading profile: no such user");
+    assert_eq!(err.error, "USER_NOT_FOUND");
+    assert_eq!(err.status, StatusKind::Http(StatusCode::NOT_FOUND));
+}
+
+#[test]
+fn optional_fields_are_boxed_to_keep_results_small() {
+    assert!(std::mem::size_of::<AppError>() <= 64);
+}
@@ -0,0 +1,43 @@
+use http::StatusCode;
+use morde_rs::errors::StatusKind;
+
+#[test]
+fn bad_request_arm_expands() {
+    let err = morde_rs::app_error!(bad_request, "x", "y");
+    assert_eq!(err.status, StatusKind::Http(StatusCode::BAD_REQUEST));
+    assert_eq!(err.error, "x");
+    assert_eq!(err.message, "y");
+}
+
+#[test]
+fn generic_arms_expand() {
+    let err = morde_rs::app_error!(custom(1001), "RATE_LIMITED", "slow down");
+    assert_eq!(err.status, StatusKind::App(1001));
+
+    let err = morde_rs::app_error!(http(StatusCode::GONE), "GONE", "deleted", "id=42");
+    assert_eq!(err.status, StatusKind::Http(StatusCode::GONE));
+}
+
+#[test]
+fn payload_too_large_arm_expands() {
+    let err = morde_rs::app_error!(payload_too_large, 2048);
+    assert_eq!(err.status, StatusKind::Http(StatusCode::PAYLOAD_TOO_LARGE));
+    assert_eq!(err.message, "request body exceeds 2048 bytes");
+}
+
+#[test]
+fn unauthorized_arm_accepts_redacted_secret() {
+    let token = String::from("secret-token-1234");
+    let err = morde_rs::app_error!(unauthorized, "invalid token"; redacted: token);
+    assert_eq!(err.status, StatusKind::Http(StatusCode::UNAUTHORIZED));
+    assert_eq!(err.message, "invalid token");
+}
+
+#[test]
+fn locking_arms_expand() {
+    let err = morde_rs::app_error!(precondition_failed, "PRECONDITION_FAILED", "etag mismatch");
+    assert_eq!(err.status, StatusKind::Http(StatusCode::PRECONDITION_FAILED));
+
+    let err = morde_rs::app_error!(locked, "document is being edited", "doc=7");
+    assert_eq!(err.status, StatusKind::Http(StatusCode::LOCKED));
+}
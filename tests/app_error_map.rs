@@ -0,0 +1,23 @@
+use std::collections::HashMap;
+
+use morde_rs::errors::{AppError, StatusKind, set_app_error_map};
+
+// The map is process-wide, so everything touching it lives in a single test.
+#[test]
+fn registered_slug_fills_blank_error() {
+    let map = HashMap::from([(1001, "RATE_LIMITED".to_string())]);
+    assert!(set_app_error_map(map).is_ok());
+    assert!(set_app_error_map(HashMap::new()).is_err());
+
+    let body = serde_json::to_value(
+        AppError::new(StatusKind::App(1001), "", "slow down").to_error_response(),
+    )
+    .unwrap();
+    assert_eq!(body["error"], "RATE_LIMITED");
+
+    let response = AppError::new(StatusKind::App(1001), "CUSTOM", "slow down").to_error_response();
+    assert_eq!(response.error, "CUSTOM");
+
+    let response = AppError::new(StatusKind::App(1002), "", "unmapped").to_error_response();
+    assert_eq!(response.error, "");
+}
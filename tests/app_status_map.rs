@@ -0,0 +1,25 @@
+use std::collections::HashMap;
+
+use http::StatusCode;
+use morde_rs::errors::{StatusKind, set_app_status_map};
+
+// The map is process-wide, so everything touching it lives in a single test.
+#[test]
+fn registered_map_is_consulted_by_to_http_status() {
+    let map = HashMap::from([
+        (1001, StatusCode::BAD_REQUEST),
+        (1002, StatusCode::CONFLICT),
+    ]);
+    assert!(set_app_status_map(map).is_ok());
+    assert!(set_app_status_map(HashMap::new()).is_err());
+
+    assert_eq!(
+        StatusKind::App(1001).to_http_status(),
+        StatusCode::BAD_REQUEST
+    );
+    assert_eq!(StatusKind::App(1002).to_http_status(), StatusCode::CONFLICT);
+    assert_eq!(
+        StatusKind::App(1003).to_http_status(),
+        StatusCode::INTERNAL_SERVER_ERROR
+    );
+}
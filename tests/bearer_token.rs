@@ -0,0 +1,57 @@
+#![cfg(feature = "axum")]
+
+use axum::{Router, body::Body, routing::get};
+use http::{Request, StatusCode, header};
+use morde_rs::extract::BearerToken;
+use tower::ServiceExt;
+
+async fn whoami(BearerToken(token): BearerToken) -> String {
+    token
+}
+
+async fn get_with(authorization: Option<&'static str>) -> (StatusCode, String) {
+    let app = Router::new().route("/me", get(whoami));
+    let mut request = Request::get("/me");
+    if let Some(value) = authorization {
+        request = request.header(header::AUTHORIZATION, value);
+    }
+
+    let response = app
+        .oneshot(request.body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+
+    (status, String::from_utf8(body.to_vec()).unwrap())
+}
+
+#[tokio::test]
+async fn present_bearer_token_is_extracted() {
+    let (status, body) = get_with(Some("Bearer abc.def.ghi")).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body, "abc.def.ghi");
+}
+
+#[tokio::test]
+async fn bearer_scheme_is_case_insensitive() {
+    let (status, body) = get_with(Some("bearer abc.def.ghi")).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body, "abc.def.ghi");
+}
+
+#[tokio::test]
+async fn absent_header_is_rejected() {
+    let (status, body) = get_with(None).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    assert!(body.contains("MISSING_TOKEN"));
+}
+
+#[tokio::test]
+async fn non_bearer_scheme_is_rejected() {
+    let (status, body) = get_with(Some("Basic dXNlcjpwYXNz")).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    assert!(body.contains("MISSING_TOKEN"));
+}
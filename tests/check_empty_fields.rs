@@ -36,3 +36,137 @@ fn non_string_some_is_not_considered_missing() {
     // id is Some(i32) (downcast fails -> treated as present), name is present
     assert!(missing.is_empty());
 }
+
+
+#[test]
+fn trim_mode_reports_whitespace_only_strings() {
+    struct Payload {
+        name: Option<String>,
+        email: Option<String>,
+    }
+
+    let payload = Payload { name: Some("  \t ".to_string()), email: Some("bob@example.com".to_string()) };
+    assert!(morde_rs::check_empty_fields!(payload, [name, email]).is_empty());
+
+    let missing = morde_rs::check_empty_fields!(payload, [name, email], trim);
+    assert_eq!(missing, vec!["name"]);
+}
+
+
+#[test]
+fn nested_paths_report_full_path() {
+    struct Address {
+        street: Option<String>,
+        city: Option<String>,
+    }
+
+    struct Payload {
+        address: Option<Address>,
+    }
+
+    let payload = Payload {
+        address: Some(Address { street: Some("Main St".to_string()), city: Some("".to_string()) }),
+    };
+    let missing = morde_rs::check_empty_fields!(payload, [address.street, address.city]);
+    assert_eq!(missing, vec!["address.city"]);
+}
+
+
+#[test]
+fn none_intermediate_reports_leaf_path() {
+    struct Address {
+        street: Option<String>,
+    }
+
+    struct Payload {
+        name: Option<String>,
+        address: Option<Address>,
+    }
+
+    let payload = Payload { name: Some("bob".to_string()), address: None };
+    let missing = morde_rs::check_empty_fields!(payload, [name, address.street]);
+    assert_eq!(missing, vec!["address.street"]);
+}
+
+
+#[test]
+fn collections_mode_reports_empty_vecs() {
+    struct Payload {
+        tags: Option<Vec<i32>>,
+        ids: Option<Vec<i32>>,
+        name: Option<String>,
+    }
+
+    let payload = Payload { tags: Some(Vec::new()), ids: Some(vec![1]), name: Some("".to_string()) };
+    assert_eq!(morde_rs::check_empty_fields!(payload, [tags, ids, name]), vec!["name"]);
+
+    let missing = morde_rs::check_empty_fields!(payload, [tags, ids, name], collections);
+    assert_eq!(missing, vec!["tags", "name"]);
+}
+
+
+#[test]
+fn predicate_overrides_default_check() {
+    struct Payload {
+        name: Option<String>,
+        phone: Option<String>,
+    }
+
+    let payload = Payload { name: Some("bob".to_string()), phone: Some("12345".to_string()) };
+    let missing = morde_rs::check_empty_fields!(payload, [name, phone => |s| s.len() < 7]);
+    assert_eq!(missing, vec!["phone"]);
+
+    let payload = Payload { name: Some("".to_string()), phone: Some("5551234567".to_string()) };
+    let missing = morde_rs::check_empty_fields!(payload, [name, phone => |s| s.len() < 7]);
+    assert_eq!(missing, vec!["name"]);
+}
+
+#[test]
+fn static_cow_and_str_fields_are_checked() {
+    use std::borrow::Cow;
+
+    struct Payload {
+        bio: Option<Cow<'static, str>>,
+        tag: Option<&'static str>,
+    }
+
+    let payload = Payload { bio: Some(Cow::Borrowed("")), tag: Some("") };
+    let missing = morde_rs::check_empty_fields!(payload, [bio, tag]);
+    assert_eq!(missing, vec!["bio", "tag"]);
+}
+
+#[test]
+fn str_mode_handles_borrowed_fields() {
+    use std::borrow::Cow;
+
+    struct Payload<'a> {
+        bio: Option<Cow<'a, str>>,
+        name: Option<&'a str>,
+        email: Option<&'a str>,
+    }
+
+    let owned = String::new();
+    let payload = Payload { bio: Some(Cow::Borrowed(&owned)), name: Some("bob"), email: None };
+    let missing = morde_rs::check_empty_fields!(payload, [bio, name, email], str);
+    assert_eq!(missing, vec!["bio", "email"]);
+}
+
+#[test]
+fn into_form_accumulates_into_existing_vec() {
+    struct User {
+        name: Option<String>,
+    }
+
+    struct Address {
+        street: Option<String>,
+        city: Option<String>,
+    }
+
+    let user = User { name: None };
+    let address = Address { street: Some("".to_string()), city: Some("Paris".to_string()) };
+
+    let mut missing = Vec::new();
+    morde_rs::check_empty_fields!(into missing, user, [name]);
+    morde_rs::check_empty_fields!(into missing, address, [street, city]);
+    assert_eq!(missing, vec!["name", "street"]);
+}
@@ -0,0 +1,16 @@
+use morde_rs::check_empty_fields_dyn;
+use serde_json::json;
+
+#[test]
+fn reports_missing_null_and_empty_keys() {
+    let payload = json!({ "name": "", "email": null, "age": 30, "bio": "hi" });
+    let missing = check_empty_fields_dyn(&payload, &["name", "email", "age", "bio", "phone"]);
+    assert_eq!(missing, vec!["name", "email", "phone"]);
+}
+
+#[test]
+fn dotted_keys_are_matched_literally() {
+    let payload = json!({ "a.b": "set", "address": { "city": "" } });
+    let missing = check_empty_fields_dyn(&payload, &["a.b", "address.city"]);
+    assert_eq!(missing, vec!["address.city"]);
+}
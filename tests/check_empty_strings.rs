@@ -0,0 +1,26 @@
+#[test]
+fn reports_empty_and_blank_plain_strings() {
+    struct Payload {
+        name: String,
+        nickname: String,
+        email: String,
+    }
+
+    let payload = Payload {
+        name: "".to_string(),
+        nickname: "   ".to_string(),
+        email: "bob@example.com".to_string(),
+    };
+    let missing = morde_rs::check_empty_strings!(payload, [name, nickname, email]);
+    assert_eq!(missing, vec!["name", "nickname"]);
+}
+
+#[test]
+fn accepts_str_slices() {
+    struct Payload<'a> {
+        name: &'a str,
+    }
+
+    let payload = Payload { name: "alice" };
+    assert!(morde_rs::check_empty_strings!(payload, [name]).is_empty());
+}
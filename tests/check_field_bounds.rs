@@ -0,0 +1,69 @@
+struct Payload {
+    name: Option<String>,
+    bio: String,
+    age: u32,
+}
+
+#[test]
+fn empty_field_is_reported() {
+    let payload = Payload {
+        name: Some("".to_string()),
+        bio: "hi".to_string(),
+        age: 30,
+    };
+    let violations = morde_rs::check_field_bounds!(payload, [name: 1..=64, bio: 1..=280]);
+    assert_eq!(violations, vec![("name", "empty")]);
+
+    let payload = Payload {
+        name: None,
+        bio: "hi".to_string(),
+        age: 30,
+    };
+    let violations = morde_rs::check_field_bounds!(payload, [name: 1..=64]);
+    assert_eq!(violations, vec![("name", "empty")]);
+}
+
+#[test]
+fn too_long_field_is_reported() {
+    let payload = Payload {
+        name: Some("a".repeat(65)),
+        bio: "é".repeat(280),
+        age: 30,
+    };
+    let violations = morde_rs::check_field_bounds!(payload, [name: 1..=64, bio: 1..=280]);
+    assert_eq!(violations, vec![("name", "too_long")]);
+}
+
+#[test]
+fn in_range_and_non_string_fields_pass() {
+    let payload = Payload {
+        name: Some("bob".to_string()),
+        bio: "hi".to_string(),
+        age: 0,
+    };
+    let violations =
+        morde_rs::check_field_bounds!(payload, [name: 1..=64, bio: 1..=280, age: 1..=3]);
+    assert!(violations.is_empty());
+}
+
+#[test]
+fn empty_value_passes_when_range_allows_zero() {
+    let payload = Payload {
+        name: Some("bob".to_string()),
+        bio: "".to_string(),
+        age: 30,
+    };
+    let violations = morde_rs::check_field_bounds!(payload, [bio: 0..=280]);
+    assert!(violations.is_empty());
+}
+
+#[test]
+fn short_non_empty_value_is_too_short() {
+    let payload = Payload {
+        name: Some("bob".to_string()),
+        bio: "ab".to_string(),
+        age: 30,
+    };
+    let violations = morde_rs::check_field_bounds!(payload, [bio: 3..=280]);
+    assert_eq!(violations, vec![("bio", "too_short")]);
+}
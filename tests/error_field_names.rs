@@ -0,0 +1,31 @@
+use morde_rs::errors::{AppError, set_error_field_names};
+use serde_json::json;
+
+// The names are process-wide, so everything touching them lives in a single test.
+#[test]
+fn registered_names_replace_error_and_message_keys() {
+    assert_eq!(set_error_field_names("code", "msg"), Err(("code", "msg")));
+    assert_eq!(set_error_field_names("err", "errors"), Err(("err", "errors")));
+    assert_eq!(set_error_field_names("same", "same"), Err(("same", "same")));
+
+    assert!(set_error_field_names("error_code", "error_message").is_ok());
+    assert!(set_error_field_names("error", "message").is_err());
+
+    let response = AppError::not_found("USER_NOT_FOUND", "no such user")
+        .with_code("E404")
+        .to_error_response();
+    assert_eq!(
+        serde_json::to_value(response).unwrap(),
+        json!({
+            "error_code": "USER_NOT_FOUND",
+            "error_message": "no such user",
+            "code": "E404",
+        })
+    );
+
+    // Errors the crate builds itself pick up the names too.
+    let err = AppError::from(serde_json::from_str::<u8>("x").unwrap_err());
+    let value = serde_json::to_value(err.to_error_response()).unwrap();
+    assert!(value.get("error_code").is_some());
+    assert!(value.get("error").is_none());
+}
@@ -0,0 +1,83 @@
+use morde_rs::errors::{AppError, ErrorResponse};
+use serde_json::json;
+
+#[test]
+fn optional_fields_omitted_when_none() {
+    let response = ErrorResponse {
+        error: "NOT_FOUND".to_string(),
+        message: "user not found".to_string(),
+        code: None,
+        details: None,
+        trace_id: None,
+        timestamp: None,
+        errors: None,
+    };
+
+    let value = serde_json::to_value(&response).unwrap();
+    assert_eq!(
+        value,
+        json!({ "error": "NOT_FOUND", "message": "user not found" })
+    );
+}
+
+#[test]
+fn optional_fields_present_when_set() {
+    let response = ErrorResponse {
+        error: "VALIDATION".to_string(),
+        message: "invalid payload".to_string(),
+        code: Some("EMAIL_REQUIRED".to_string()),
+        details: Some(json!({ "email": "required" })),
+        trace_id: Some("abc123".to_string()),
+        timestamp: Some("2024-05-01T12:00:00Z".to_string()),
+        errors: None,
+    };
+
+    let value = serde_json::to_value(&response).unwrap();
+    assert_eq!(value["details"], json!({ "email": "required" }));
+    assert_eq!(value["code"], "EMAIL_REQUIRED");
+    assert_eq!(value["trace_id"], "abc123");
+    assert_eq!(value["timestamp"], "2024-05-01T12:00:00Z");
+}
+
+#[test]
+fn with_code_is_carried_into_response_body() {
+    let err = morde_rs::errors::AppError::not_found("Not Found", "user not found")
+        .with_code("USER_NOT_FOUND");
+
+    let value = serde_json::to_value(err.to_error_response()).unwrap();
+    assert_eq!(value["code"], "USER_NOT_FOUND");
+    assert_eq!(value["error"], "Not Found");
+}
+
+#[test]
+fn empty_error_falls_back_to_reason_phrase() {
+    use morde_rs::errors::StatusKind;
+
+    let err = AppError::new(StatusKind::Http(http::StatusCode::NOT_FOUND), "", "user not found");
+    assert_eq!(err.to_error_response().error, "Not Found");
+
+    let err = AppError::new(StatusKind::App(1001), "", "slow down");
+    assert_eq!(err.to_error_response().error, "");
+}
+
+#[test]
+fn validation_errors_serialize_as_array() {
+    let err = AppError::validation(vec![
+        ("email".to_string(), "is required".to_string()),
+        ("age".to_string(), "must be positive".to_string()),
+    ]);
+    assert_eq!(err.status.to_http_status(), http::StatusCode::UNPROCESSABLE_ENTITY);
+
+    let value = serde_json::to_value(err.to_error_response()).unwrap();
+    assert_eq!(
+        value,
+        json!({
+            "error": "VALIDATION",
+            "message": "Validation failed",
+            "errors": [
+                { "field": "email", "message": "is required" },
+                { "field": "age", "message": "must be positive" },
+            ],
+        })
+    );
+}
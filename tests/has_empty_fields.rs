@@ -0,0 +1,28 @@
+struct Payload {
+    name: Option<String>,
+    email: Option<String>,
+}
+
+#[test]
+fn all_present_returns_false() {
+    let payload = Payload {
+        name: Some("bob".to_string()),
+        email: Some("bob@example.com".to_string()),
+    };
+    assert!(!morde_rs::has_empty_fields!(payload, [name, email]));
+}
+
+#[test]
+fn one_missing_returns_true() {
+    let payload = Payload {
+        name: Some("bob".to_string()),
+        email: None,
+    };
+    assert!(morde_rs::has_empty_fields!(payload, [name, email]));
+
+    let payload = Payload {
+        name: Some("".to_string()),
+        email: Some("bob@example.com".to_string()),
+    };
+    assert!(morde_rs::has_empty_fields!(payload, [name, email]));
+}
@@ -0,0 +1,110 @@
+#![cfg(feature = "axum")]
+
+use std::time::Duration;
+
+use axum::response::IntoResponse;
+use http::{StatusCode, header};
+use morde_rs::errors::AppError;
+
+#[test]
+fn too_many_requests_sets_retry_after() {
+    let response =
+        AppError::too_many_requests("slow down", Some(Duration::from_secs(30))).into_response();
+
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    assert_eq!(response.headers()[header::RETRY_AFTER], "30");
+}
+
+#[test]
+fn too_many_requests_rounds_retry_after_up() {
+    let response =
+        AppError::too_many_requests("slow down", Some(Duration::from_millis(1500))).into_response();
+    assert_eq!(response.headers()[header::RETRY_AFTER], "2");
+
+    let response =
+        AppError::too_many_requests("slow down", Some(Duration::from_millis(200))).into_response();
+    assert_eq!(response.headers()[header::RETRY_AFTER], "1");
+
+    let response = AppError::too_many_requests("slow down", Some(Duration::MAX)).into_response();
+    assert_eq!(response.headers()[header::RETRY_AFTER], u64::MAX.to_string());
+}
+
+#[test]
+fn too_many_requests_without_retry_after_has_no_header() {
+    let response = AppError::too_many_requests("slow down", None).into_response();
+
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    assert!(response.headers().get(header::RETRY_AFTER).is_none());
+}
+
+#[test]
+fn custom_headers_survive_conversion() {
+    let response = AppError::unauthorized("missing token")
+        .with_header(header::WWW_AUTHENTICATE, "Bearer".parse().unwrap())
+        .into_response();
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    assert_eq!(response.headers()[header::WWW_AUTHENTICATE], "Bearer");
+}
+
+#[tokio::test]
+async fn explicit_trace_id_is_serialized() {
+    let response = AppError::not_found("USER", "user not found")
+        .with_trace_id("req-42")
+        .into_response();
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let value: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(value["trace_id"], "req-42");
+}
+
+#[tokio::test]
+async fn built_error_survives_conversion() {
+    let response = AppError::build(StatusCode::CONFLICT)
+        .error("DUPLICATE_EMAIL")
+        .message("email is already registered")
+        .code("E1002")
+        .details(serde_json::json!({ "field": "email" }))
+        .header(header::LOCATION, "/users/42".parse().unwrap())
+        .build()
+        .into_response();
+
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+    assert_eq!(response.headers()[header::LOCATION], "/users/42");
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let value: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(
+        value,
+        serde_json::json!({
+            "error": "DUPLICATE_EMAIL",
+            "message": "email is already registered",
+            "code": "E1002",
+            "details": { "field": "email" },
+        })
+    );
+}
+
+#[test]
+#[tracing_test::traced_test]
+fn into_response_emits_structured_event() {
+    let _ = AppError::not_found("USER_NOT_FOUND", "no such user").into_response();
+
+    assert!(logs_contain("status=404"));
+    assert!(logs_contain("error=USER_NOT_FOUND"));
+    assert!(logs_contain("no such user"));
+}
+
+#[test]
+#[tracing_test::traced_test]
+fn into_response_logs_the_source_error() {
+    let source: Box<dyn std::error::Error + Send + Sync> = "db exploded".into();
+    let response = AppError::from(source).into_response();
+
+    assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    assert!(logs_contain("source=db exploded"));
+}
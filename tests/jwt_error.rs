@@ -0,0 +1,26 @@
+#![cfg(feature = "jwt")]
+
+use http::StatusCode;
+use jsonwebtoken::errors::{Error, ErrorKind};
+use morde_rs::errors::{AppError, StatusKind};
+
+#[test]
+fn expired_signature_maps_to_token_expired() {
+    let err = AppError::from(Error::from(ErrorKind::ExpiredSignature));
+    assert_eq!(err.status, StatusKind::Http(StatusCode::UNAUTHORIZED));
+    assert_eq!(err.error, "TOKEN_EXPIRED");
+}
+
+#[test]
+fn invalid_signature_maps_to_invalid_signature() {
+    let err = AppError::from(Error::from(ErrorKind::InvalidSignature));
+    assert_eq!(err.status, StatusKind::Http(StatusCode::UNAUTHORIZED));
+    assert_eq!(err.error, "INVALID_SIGNATURE");
+}
+
+#[test]
+fn other_kinds_map_to_invalid_token() {
+    let err = AppError::from(Error::from(ErrorKind::InvalidToken));
+    assert_eq!(err.status, StatusKind::Http(StatusCode::UNAUTHORIZED));
+    assert_eq!(err.error, "INVALID_TOKEN");
+}
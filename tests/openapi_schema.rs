@@ -0,0 +1,25 @@
+#![cfg(feature = "openapi")]
+
+use morde_rs::errors::{ErrorResponse, FieldError};
+use utoipa::PartialSchema;
+
+#[test]
+fn error_response_schema_has_error_and_message() {
+    let schema = serde_json::to_value(ErrorResponse::schema()).unwrap();
+
+    assert_eq!(schema["properties"]["error"]["type"], "string");
+    assert_eq!(schema["properties"]["message"]["type"], "string");
+    assert_eq!(schema["properties"]["error"]["example"], "USER_NOT_FOUND");
+
+    let required = schema["required"].as_array().unwrap();
+    assert!(required.contains(&"error".into()));
+    assert!(required.contains(&"message".into()));
+}
+
+#[test]
+fn field_error_schema_has_field_and_message() {
+    let schema = serde_json::to_value(FieldError::schema()).unwrap();
+
+    assert_eq!(schema["properties"]["field"]["type"], "string");
+    assert_eq!(schema["properties"]["message"]["type"], "string");
+}
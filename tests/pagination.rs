@@ -0,0 +1,22 @@
+use http::StatusCode;
+use morde_rs::pagination::validate_page;
+
+#[test]
+fn in_range_limit_is_accepted() {
+    assert!(validate_page(1, 1, 100).is_ok());
+    assert!(validate_page(3, 100, 100).is_ok());
+}
+
+#[test]
+fn zero_limit_is_rejected() {
+    let err = validate_page(1, 0, 100).unwrap_err();
+    assert_eq!(err.status.to_http_status(), StatusCode::BAD_REQUEST);
+    assert_eq!(err.error, "INVALID_PAGINATION");
+}
+
+#[test]
+fn over_max_limit_is_rejected() {
+    let err = validate_page(1, 101, 100).unwrap_err();
+    assert_eq!(err.error, "INVALID_PAGINATION");
+    assert_eq!(err.message, "limit must be between 1 and 100");
+}
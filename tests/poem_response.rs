@@ -0,0 +1,17 @@
+#![cfg(feature = "poem")]
+
+use http::StatusCode;
+use morde_rs::errors::AppError;
+use serde_json::json;
+
+#[tokio::test]
+async fn poem_error_uses_mapped_status_and_json_body() {
+    let err = poem::Error::from(AppError::conflict("DUPLICATE", "already exists"));
+    assert_eq!(err.status(), StatusCode::CONFLICT);
+
+    let response = err.into_response();
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+
+    let body: serde_json::Value = response.into_body().into_json().await.unwrap();
+    assert_eq!(body, json!({ "error": "DUPLICATE", "message": "already exists" }));
+}
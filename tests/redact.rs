@@ -0,0 +1,12 @@
+use morde_rs::errors::redact;
+
+#[test]
+fn masks_all_but_last_four() {
+    assert_eq!(redact("abcdef1234"), "******1234");
+}
+
+#[test]
+fn short_secrets_are_fully_masked() {
+    assert_eq!(redact("abcd"), "****");
+    assert_eq!(redact(""), "");
+}
@@ -0,0 +1,36 @@
+#![cfg(feature = "redis")]
+
+use std::io;
+
+use http::StatusCode;
+use morde_rs::errors::{AppError, StatusKind};
+use redis::{ErrorKind, RedisError};
+
+#[test]
+fn connection_refused_maps_to_503() {
+    let err = RedisError::from(io::Error::from(io::ErrorKind::ConnectionRefused));
+
+    let err = AppError::from(err);
+    assert_eq!(err.status, StatusKind::Http(StatusCode::SERVICE_UNAVAILABLE));
+    assert_eq!(err.error, "CACHE_ERROR");
+    assert_eq!(err.message, "Cache unavailable");
+}
+
+#[test]
+fn timeout_maps_to_503() {
+    let err = RedisError::from(io::Error::from(io::ErrorKind::TimedOut));
+
+    let err = AppError::from(err);
+    assert_eq!(err.status, StatusKind::Http(StatusCode::SERVICE_UNAVAILABLE));
+}
+
+#[test]
+fn other_errors_map_to_500_without_leaking_details() {
+    let err = RedisError::from((ErrorKind::TypeError, "WRONGTYPE on key session:42"));
+
+    let err = AppError::from(err);
+    assert_eq!(err.status, StatusKind::Http(StatusCode::INTERNAL_SERVER_ERROR));
+    assert_eq!(err.error, "CACHE_ERROR");
+    assert_eq!(err.message, "Cache error");
+    assert!(err.extras.source.is_some());
+}
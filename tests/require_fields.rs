@@ -0,0 +1,29 @@
+use http::StatusCode;
+use morde_rs::errors::AppError;
+use serde_json::json;
+
+struct Payload {
+    name: Option<String>,
+    email: Option<String>,
+}
+
+fn handler(payload: &Payload) -> Result<(), AppError> {
+    morde_rs::require_fields!(payload, [name, email])?;
+    Ok(())
+}
+
+#[test]
+fn missing_fields_return_bad_request_with_details() {
+    let payload = Payload { name: Some("".to_string()), email: None };
+    let err = handler(&payload).unwrap_err();
+
+    assert_eq!(err.status.to_http_status(), StatusCode::BAD_REQUEST);
+    assert_eq!(err.error, "MISSING_FIELDS");
+    assert_eq!(err.extras.details, Some(json!({ "fields": ["name", "email"] })));
+}
+
+#[test]
+fn all_present_is_ok() {
+    let payload = Payload { name: Some("bob".to_string()), email: Some("bob@example.com".to_string()) };
+    assert!(handler(&payload).is_ok());
+}
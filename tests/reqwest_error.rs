@@ -0,0 +1,60 @@
+#![cfg(feature = "reqwest")]
+
+use std::time::Duration;
+
+use http::StatusCode;
+use morde_rs::errors::{AppError, StatusKind};
+
+#[test]
+fn upstream_status_is_preserved() {
+    let response = http::Response::builder()
+        .status(StatusCode::NOT_FOUND)
+        .body("")
+        .unwrap();
+    let err = reqwest::Response::from(response)
+        .error_for_status()
+        .unwrap_err();
+
+    let err = AppError::from(err);
+    assert_eq!(err.status, StatusKind::Http(StatusCode::NOT_FOUND));
+    assert_eq!(err.error, "UPSTREAM_ERROR");
+    assert_eq!(err.message, "Upstream request failed");
+    assert!(err.extras.source.is_some());
+}
+
+#[tokio::test]
+async fn timeout_maps_to_503() {
+    // The listener completes the TCP handshake but never answers the request.
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+
+    let err = reqwest::Client::builder()
+        .timeout(Duration::from_millis(50))
+        .build()
+        .unwrap()
+        .get(url)
+        .send()
+        .await
+        .unwrap_err();
+    assert!(err.is_timeout());
+
+    let err = AppError::from(err);
+    assert_eq!(err.status, StatusKind::Http(StatusCode::SERVICE_UNAVAILABLE));
+    assert_eq!(err.error, "UPSTREAM_ERROR");
+}
+
+#[tokio::test]
+async fn connect_error_maps_to_503() {
+    // Grab a free port and release it so the connection is refused.
+    let addr = std::net::TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap();
+
+    let err = reqwest::get(format!("http://{addr}")).await.unwrap_err();
+    assert!(err.is_connect());
+
+    let err = AppError::from(err);
+    assert_eq!(err.status, StatusKind::Http(StatusCode::SERVICE_UNAVAILABLE));
+    assert_eq!(err.error, "UPSTREAM_ERROR");
+}
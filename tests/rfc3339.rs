@@ -0,0 +1,40 @@
+#![cfg(feature = "time")]
+
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Claims {
+    #[serde(
+        serialize_with = "morde_rs::rfc3339::serialize_unix_as_rfc3339",
+        deserialize_with = "morde_rs::rfc3339::deserialize_rfc3339_as_unix"
+    )]
+    exp: u64,
+    #[serde(with = "morde_rs::rfc3339")]
+    iat: u64,
+}
+
+#[test]
+fn known_timestamp_round_trips() {
+    let claims = Claims {
+        exp: 1_700_000_000,
+        iat: 1_700_000_000,
+    };
+
+    let value = serde_json::to_value(&claims).unwrap();
+    assert_eq!(
+        value,
+        json!({ "exp": "2023-11-14T22:13:20Z", "iat": "2023-11-14T22:13:20Z" })
+    );
+
+    let parsed: Claims = serde_json::from_value(value).unwrap();
+    assert_eq!(parsed, claims);
+}
+
+#[test]
+fn invalid_string_is_rejected() {
+    let result = serde_json::from_value::<Claims>(
+        json!({ "exp": "yesterday", "iat": "2023-11-14T22:13:20Z" }),
+    );
+    assert!(result.is_err());
+}
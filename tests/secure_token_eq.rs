@@ -0,0 +1,16 @@
+#![cfg(feature = "jwt")]
+
+use morde_rs::jwt::secure_token_eq;
+
+#[test]
+fn equal_tokens_match() {
+    assert!(secure_token_eq("0b5c1e9a-jti", "0b5c1e9a-jti"));
+    assert!(secure_token_eq("", ""));
+}
+
+#[test]
+fn different_tokens_do_not_match() {
+    assert!(!secure_token_eq("0b5c1e9a-jti", "0b5c1e9b-jti"));
+    assert!(!secure_token_eq("0b5c1e9a-jti", "0b5c1e9a"));
+    assert!(!secure_token_eq("", "x"));
+}
@@ -0,0 +1,73 @@
+#![cfg(feature = "sqlx")]
+
+use std::borrow::Cow;
+use std::error::Error;
+use std::fmt;
+
+use http::StatusCode;
+use morde_rs::errors::{AppError, StatusKind};
+use sqlx::error::{DatabaseError, ErrorKind};
+
+#[derive(Debug)]
+struct FakeDbError {
+    code: &'static str,
+}
+
+impl fmt::Display for FakeDbError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "database error {}", self.code)
+    }
+}
+
+impl Error for FakeDbError {}
+
+impl DatabaseError for FakeDbError {
+    fn message(&self) -> &str {
+        "fake database error"
+    }
+
+    fn code(&self) -> Option<Cow<'_, str>> {
+        Some(Cow::Borrowed(self.code))
+    }
+
+    fn as_error(&self) -> &(dyn Error + Send + Sync + 'static) {
+        self
+    }
+
+    fn as_error_mut(&mut self) -> &mut (dyn Error + Send + Sync + 'static) {
+        self
+    }
+
+    fn into_error(self: Box<Self>) -> Box<dyn Error + Send + Sync + 'static> {
+        self
+    }
+
+    fn kind(&self) -> ErrorKind {
+        ErrorKind::Other
+    }
+}
+
+#[test]
+fn row_not_found_maps_to_404() {
+    let err = AppError::from(sqlx::Error::RowNotFound);
+    assert_eq!(err.status, StatusKind::Http(StatusCode::NOT_FOUND));
+    assert_eq!(err.error, "NOT_FOUND");
+}
+
+#[test]
+fn unique_violation_maps_to_409() {
+    let err = AppError::from(sqlx::Error::Database(Box::new(FakeDbError { code: "23505" })));
+    assert_eq!(err.status, StatusKind::Http(StatusCode::CONFLICT));
+    assert_eq!(err.error, "CONFLICT");
+}
+
+#[test]
+fn other_errors_map_to_500_without_leaking_details() {
+    let err = AppError::from(sqlx::Error::Database(Box::new(FakeDbError { code: "42P01" })));
+    assert_eq!(err.status, StatusKind::Http(StatusCode::INTERNAL_SERVER_ERROR));
+    assert!(!err.message.contains("42P01"));
+    assert!(err.source().is_some());
+
+    let err = AppError::from(sqlx::Error::PoolTimedOut);
+    assert_eq!(err.status, StatusKind::Http(StatusCode::INTERNAL_SERVER_ERROR));
+}
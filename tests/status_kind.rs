@@ -0,0 +1,104 @@
+use std::collections::HashMap;
+
+use http::StatusCode;
+use morde_rs::errors::StatusKind;
+
+#[test]
+fn app_status_defaults_to_500_without_map() {
+    assert_eq!(
+        StatusKind::App(1001).to_http_status(),
+        StatusCode::INTERNAL_SERVER_ERROR
+    );
+    assert_eq!(
+        StatusKind::Http(StatusCode::NOT_FOUND).to_http_status(),
+        StatusCode::NOT_FOUND
+    );
+}
+
+#[test]
+fn to_http_status_with_uses_given_map() {
+    let map = HashMap::from([
+        (1001, StatusCode::BAD_REQUEST),
+        (1002, StatusCode::CONFLICT),
+    ]);
+
+    assert_eq!(
+        StatusKind::App(1001).to_http_status_with(&map),
+        StatusCode::BAD_REQUEST
+    );
+    assert_eq!(
+        StatusKind::App(1002).to_http_status_with(&map),
+        StatusCode::CONFLICT
+    );
+    assert_eq!(
+        StatusKind::App(1003).to_http_status_with(&map),
+        StatusCode::INTERNAL_SERVER_ERROR
+    );
+}
+
+#[test]
+fn from_http_u16_prefers_standard_http_codes() {
+    assert_eq!(
+        StatusKind::from_http_u16(404),
+        StatusKind::Http(StatusCode::NOT_FOUND)
+    );
+    assert_eq!(StatusKind::from_http_u16(1001), StatusKind::App(1001));
+    // In the valid HTTP range but not a standard code.
+    assert_eq!(StatusKind::from_http_u16(599), StatusKind::App(599));
+
+    assert_eq!(StatusKind::from(404u16), StatusKind::App(404));
+}
+
+#[test]
+fn as_u16_returns_raw_number() {
+    assert_eq!(StatusKind::App(1001).as_u16(), 1001);
+    assert_eq!(StatusKind::Http(StatusCode::NOT_FOUND).as_u16(), 404);
+}
+
+#[test]
+fn deserializes_bare_integers_and_tagged_objects() {
+    let kind: StatusKind = serde_json::from_str("404").unwrap();
+    assert_eq!(kind, StatusKind::Http(StatusCode::NOT_FOUND));
+
+    let kind: StatusKind = serde_json::from_str("1001").unwrap();
+    assert_eq!(kind, StatusKind::App(1001));
+
+    let kind: StatusKind = serde_json::from_str(r#"{"app": 1001}"#).unwrap();
+    assert_eq!(kind, StatusKind::App(1001));
+
+    let kind: StatusKind = serde_json::from_str(r#"{"http": 409}"#).unwrap();
+    assert_eq!(kind, StatusKind::Http(StatusCode::CONFLICT));
+
+    assert!(serde_json::from_str::<StatusKind>(r#"{"nope": 1}"#).is_err());
+}
+
+#[test]
+fn tagged_object_with_extra_keys_is_rejected() {
+    let err = serde_json::from_str::<StatusKind>(r#"{"app": 1, "http": 2}"#).unwrap_err();
+    assert!(err.to_string().contains("expected exactly one of `app`/`http`"));
+
+    assert!(serde_json::from_str::<StatusKind>(r#"{"http": 404, "extra": 1}"#).is_err());
+}
+
+#[test]
+fn serde_round_trips_both_forms() {
+    for kind in [
+        StatusKind::Http(StatusCode::NOT_FOUND),
+        StatusKind::App(1001),
+        StatusKind::App(404),
+        StatusKind::Http(StatusCode::from_u16(599).unwrap()),
+    ] {
+        let json = serde_json::to_string(&kind).unwrap();
+        assert_eq!(serde_json::from_str::<StatusKind>(&json).unwrap(), kind);
+    }
+
+    assert_eq!(serde_json::to_string(&StatusKind::Http(StatusCode::NOT_FOUND)).unwrap(), "404");
+    assert_eq!(serde_json::to_string(&StatusKind::App(1001)).unwrap(), r#"{"app":1001}"#);
+}
+
+#[test]
+fn status_kind_is_copy() {
+    let status = StatusKind::App(1001);
+    let copied = status;
+    assert_eq!(status, copied);
+}
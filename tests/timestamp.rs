@@ -0,0 +1,21 @@
+#![cfg(feature = "time")]
+
+use morde_rs::errors::AppError;
+use time::OffsetDateTime;
+use time::format_description::well_known::Rfc3339;
+
+#[test]
+fn with_timestamp_produces_rfc3339() {
+    let response = AppError::bad_request("INVALID", "bad input")
+        .with_timestamp()
+        .to_error_response();
+
+    let timestamp = response.timestamp.expect("timestamp should be set");
+    assert!(OffsetDateTime::parse(&timestamp, &Rfc3339).is_ok());
+}
+
+#[test]
+fn timestamp_is_omitted_by_default() {
+    let response = AppError::bad_request("INVALID", "bad input").to_error_response();
+    assert!(response.timestamp.is_none());
+}
@@ -0,0 +1,29 @@
+#![cfg(feature = "tonic")]
+
+use morde_rs::errors::AppError;
+use tonic::{Code, Status};
+
+#[test]
+fn http_statuses_map_to_grpc_codes() {
+    let cases = [
+        (AppError::not_found("USER", "user not found"), Code::NotFound),
+        (AppError::unauthorized("no token"), Code::Unauthenticated),
+        (AppError::forbidden("not yours"), Code::PermissionDenied),
+        (AppError::conflict("DUPLICATE", "exists"), Code::AlreadyExists),
+        (AppError::bad_request("INVALID", "bad input"), Code::InvalidArgument),
+        (AppError::internal_server_error("boom"), Code::Internal),
+    ];
+
+    for (err, code) in cases {
+        let message = err.message.clone();
+        let status = Status::from(err);
+        assert_eq!(status.code(), code);
+        assert_eq!(status.message(), message);
+    }
+}
+
+#[test]
+fn app_statuses_map_through_http_status() {
+    let err = AppError::new(1001.into(), "RATE_LIMITED", "slow down");
+    assert_eq!(Status::from(err).code(), Code::Internal);
+}
@@ -0,0 +1,35 @@
+#![cfg(feature = "axum")]
+
+use axum::response::{IntoResponse, Response};
+use morde_rs::errors::{AppError, set_trace_id_extractor};
+
+async fn trace_id(response: Response) -> serde_json::Value {
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let value: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    value["trace_id"].clone()
+}
+
+// The extractor is process-wide, so everything touching it lives in a single test.
+#[tokio::test]
+#[tracing_test::traced_test]
+async fn registered_extractor_reads_trace_id_from_current_span() {
+    fn span_name(span: &tracing::Span) -> Option<String> {
+        span.metadata().map(|meta| meta.name().to_string())
+    }
+    assert!(set_trace_id_extractor(span_name).is_ok());
+    assert!(set_trace_id_extractor(|_| None).is_err());
+
+    let (from_span, explicit) = tracing::info_span!("checkout").in_scope(|| {
+        (
+            AppError::not_found("USER", "user not found").into_response(),
+            AppError::not_found("USER", "user not found")
+                .with_trace_id("req-42")
+                .into_response(),
+        )
+    });
+
+    assert_eq!(trace_id(from_span).await, "checkout");
+    assert_eq!(trace_id(explicit).await, "req-42");
+}
@@ -0,0 +1,8 @@
+struct Payload {
+    name: Option<String>,
+}
+
+fn main() {
+    let payload = Payload { name: None };
+    let _ = morde_rs::check_empty_fields!(payload, [nam]);
+}
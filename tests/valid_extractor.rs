@@ -0,0 +1,71 @@
+#![cfg(feature = "axum")]
+
+use axum::{Router, body::Body, routing::post};
+use http::{Request, StatusCode, header};
+use morde_rs::errors::AppError;
+use morde_rs::extract::{Valid, Validate};
+use tower::ServiceExt;
+
+#[derive(serde::Deserialize)]
+struct CreateUser {
+    name: Option<String>,
+}
+
+impl Validate for CreateUser {
+    fn validate(&self) -> Result<(), AppError> {
+        morde_rs::require_fields!(self, [name])
+    }
+}
+
+async fn create_user(Valid(payload): Valid<CreateUser>) -> String {
+    payload.name.unwrap_or_default()
+}
+
+async fn post_json(body: &'static str) -> (StatusCode, serde_json::Value) {
+    send(
+        Request::post("/users")
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(body))
+            .unwrap(),
+    )
+    .await
+}
+
+async fn send(request: Request<Body>) -> (StatusCode, serde_json::Value) {
+    let app = Router::new().route("/users", post(create_user));
+    let response = app.oneshot(request).await.unwrap();
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+
+    (status, serde_json::from_slice(&body).unwrap_or(serde_json::Value::Null))
+}
+
+#[tokio::test]
+async fn valid_body_reaches_handler() {
+    let (status, _) = post_json(r#"{ "name": "alice" }"#).await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn failed_validation_returns_app_error() {
+    let (status, body) = post_json(r#"{ "name": "" }"#).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["error"], "MISSING_FIELDS");
+}
+
+#[tokio::test]
+async fn malformed_json_returns_invalid_json() {
+    let (status, body) = post_json("{ nope").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["error"], "INVALID_JSON");
+}
+
+#[tokio::test]
+async fn missing_content_type_keeps_the_415() {
+    let (status, body) =
+        send(Request::post("/users").body(Body::from(r#"{ "name": "alice" }"#)).unwrap()).await;
+    assert_eq!(status, StatusCode::UNSUPPORTED_MEDIA_TYPE);
+    assert_eq!(body["error"], "INVALID_JSON");
+}
@@ -0,0 +1,52 @@
+#![cfg(feature = "validator")]
+
+use http::StatusCode;
+use morde_rs::errors::AppError;
+use serde_json::json;
+use validator::Validate;
+
+#[derive(Validate)]
+struct SignUp {
+    #[validate(email(message = "must be a valid email"))]
+    email: String,
+    #[validate(length(min = 8))]
+    password: String,
+}
+
+#[test]
+fn validation_errors_map_to_422_with_field_details() {
+    let payload = SignUp { email: "not-an-email".to_string(), password: "short".to_string() };
+    let err = AppError::from(payload.validate().unwrap_err());
+
+    assert_eq!(err.status.to_http_status(), StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(err.error, "VALIDATION");
+    assert_eq!(
+        err.extras.details,
+        Some(json!({
+            "email": ["must be a valid email"],
+            "password": ["length"],
+        }))
+    );
+}
+
+#[test]
+fn validation_errors_also_fill_the_errors_array() {
+    let payload = SignUp { email: "not-an-email".to_string(), password: "short".to_string() };
+    let err = AppError::from(payload.validate().unwrap_err());
+
+    assert_eq!(
+        serde_json::to_value(err.to_error_response()).unwrap(),
+        json!({
+            "error": "VALIDATION",
+            "message": "Validation failed",
+            "details": {
+                "email": ["must be a valid email"],
+                "password": ["length"],
+            },
+            "errors": [
+                { "field": "email", "message": "must be a valid email" },
+                { "field": "password", "message": "length" },
+            ],
+        })
+    );
+}
@@ -0,0 +1,70 @@
+#![cfg(feature = "warp")]
+
+use http::StatusCode;
+use morde_rs::errors::{AppError, recover};
+use serde_json::json;
+use warp::Filter;
+
+#[tokio::test]
+async fn rejected_app_error_is_recovered_as_json() {
+    let route = warp::path("users")
+        .and_then(|| async {
+            Err::<String, _>(warp::reject::custom(AppError::not_found(
+                "USER_NOT_FOUND",
+                "no such user",
+            )))
+        })
+        .recover(recover);
+
+    let response = warp::test::request().path("/users").reply(&route).await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    let body: serde_json::Value = serde_json::from_slice(response.body()).unwrap();
+    assert_eq!(body, json!({ "error": "USER_NOT_FOUND", "message": "no such user" }));
+}
+
+#[tokio::test]
+async fn unmatched_route_is_recovered_as_404() {
+    let route = warp::path("users").map(|| "ok").recover(recover);
+
+    let response = warp::test::request().path("/posts").reply(&route).await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn wrong_method_is_recovered_as_405() {
+    let route = warp::post()
+        .and(warp::path("users"))
+        .map(|| "ok")
+        .recover(recover);
+
+    let response = warp::test::request()
+        .method("GET")
+        .path("/users")
+        .reply(&route)
+        .await;
+    assert_eq!(response.status(), StatusCode::METHOD_NOT_ALLOWED);
+
+    let body: serde_json::Value = serde_json::from_slice(response.body()).unwrap();
+    assert_eq!(body["error"], "METHOD_NOT_ALLOWED");
+}
+
+#[tokio::test]
+async fn malformed_query_is_recovered_as_400() {
+    #[derive(serde::Deserialize)]
+    #[allow(dead_code)]
+    struct Page {
+        page: u32,
+    }
+
+    let route = warp::path("users")
+        .and(warp::query::<Page>())
+        .map(|_: Page| "ok")
+        .recover(recover);
+
+    let response = warp::test::request()
+        .path("/users?page=abc")
+        .reply(&route)
+        .await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}